@@ -11,6 +11,10 @@ fn main() -> Result<(), Box<dyn Error>> {
     let device = I2cdev::new("/dev/i2c-1")?;
     let mut ina = SyncIna219::new_calibrated(device, Address::from_byte(0x42)?, calib)?;
 
+    // Check the device holds the calibration we wrote
+    let bits = ina.read_calibration()?;
+    assert_eq!(IntCalibration::from_bits(bits, 1_000).ok(), Some(calib));
+
     let measurement = ina.next_measurement()?.expect("A measurement is ready");
 
     println!("{:#?}", measurement);
@@ -10,8 +10,9 @@ fn main() -> Result<(), Box<dyn Error>> {
     // Wait until a result is ready
     std::thread::sleep(ina.configuration()?.conversion_time().unwrap());
 
-    println!("Bus Voltage: {}", ina.bus_voltage()?);
-    println!("Shunt Voltage: {}", ina.shunt_voltage()?);
+    let (bus_voltage, shunt_voltage) = ina.voltages()?;
+    println!("Bus Voltage: {}", bus_voltage.milli_volts());
+    println!("Shunt Voltage: {}", shunt_voltage.micro_volts());
 
     Ok(())
 }
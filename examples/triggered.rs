@@ -1,7 +1,7 @@
 use ina219::address::Address;
 use ina219::configuration::{Configuration, MeasuredSignals, OperatingMode};
 use ina219::SyncIna219;
-use linux_embedded_hal::I2cdev;
+use linux_embedded_hal::{Delay, I2cdev};
 use std::error::Error;
 use std::time::Duration;
 
@@ -39,5 +39,9 @@ fn main() -> Result<(), Box<dyn Error>> {
     println!("After trigger: {:?}", measurements);
     assert!(measurements.is_some());
 
+    // Or trigger and wait for the conversion in one go
+    let measurements = ina.trigger_and_read(&mut Delay)?;
+    println!("After trigger and read: {:?}", measurements);
+
     Ok(())
 }
@@ -17,7 +17,8 @@ use core::ops::RangeInclusive;
 ///
 /// assert_eq!(Pin::Gnd.as_byte(), 0b00);
 /// ```
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u8)]
 pub enum Pin {
     /// The pin is connected to GND
@@ -73,7 +74,7 @@ impl Pin {
 ///
 /// assert!(Address::from_byte(42).is_err());
 /// ```
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct Address {
     byte: u8,
 }
@@ -83,8 +84,50 @@ impl Address {
     const MIN_ADDRESS: u8 = *Self::VALID_ADDRESS.start();
     const MAX_ADDRESS: u8 = *Self::VALID_ADDRESS.end();
 
+    /// All combinations of the pins `(A0, A1)`, ordered by the resulting address
+    ///
+    /// # Example
+    /// ```rust
+    /// # use ina219::address::{Address, Pin};
+    ///
+    /// for (i, (a0, a1)) in Address::ALL_PIN_COMBINATIONS.into_iter().enumerate() {
+    ///     assert_eq!(Address::from_pins(a0, a1).as_byte(), 0x40 + i as u8);
+    /// }
+    /// ```
+    pub const ALL_PIN_COMBINATIONS: [(Pin, Pin); 16] = {
+        use Pin::{Gnd, Scl, Sda, Vcc};
+        [
+            (Gnd, Gnd),
+            (Vcc, Gnd),
+            (Sda, Gnd),
+            (Scl, Gnd),
+            (Gnd, Vcc),
+            (Vcc, Vcc),
+            (Sda, Vcc),
+            (Scl, Vcc),
+            (Gnd, Sda),
+            (Vcc, Sda),
+            (Sda, Sda),
+            (Scl, Sda),
+            (Gnd, Scl),
+            (Vcc, Scl),
+            (Sda, Scl),
+            (Scl, Scl),
+        ]
+    };
+
     /// Create an address from the two pins A0 and A1
     ///
+    /// Every combination of pins is a valid address. The resulting byte is
+    /// `0b100_0000 | A1 << 2 | A0` with the pins given by [`Pin::as_byte`]:
+    ///
+    /// | A1 \\ A0 | GND    | VCC    | SDA    | SCL    |
+    /// |----------|--------|--------|--------|--------|
+    /// | **GND**  | `0x40` | `0x41` | `0x42` | `0x43` |
+    /// | **VCC**  | `0x44` | `0x45` | `0x46` | `0x47` |
+    /// | **SDA**  | `0x48` | `0x49` | `0x4A` | `0x4B` |
+    /// | **SCL**  | `0x4C` | `0x4D` | `0x4E` | `0x4F` |
+    ///
     /// # Example
     /// ```rust
     /// # use ina219::address::{Address, Pin};
@@ -104,6 +147,10 @@ impl Address {
 
     /// Create an address from a byte
     ///
+    /// The byte is the 7-bit I2C address as given in table 1 of the datasheet, e.g. `0x40` when
+    /// both pins are connected to GND. If you have the 8-bit address, which includes the read/write
+    /// bit, use [`Self::from_8bit`] instead.
+    ///
     /// # Example
     /// ```rust
     /// # use ina219::address::{Address, Pin};
@@ -126,6 +173,45 @@ impl Address {
         }
     }
 
+    /// Create an address from the 8-bit representation that includes the read/write bit
+    ///
+    /// Some datasheets and tools give the address shifted left by one, e.g. `0x80` instead of
+    /// `0x40`. The lowest (read/write) bit is ignored.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use ina219::address::{Address, Pin};
+    ///
+    /// let address = Address::from_8bit(0x80).unwrap();
+    /// assert_eq!(address, Address::from_byte(0x40).unwrap());
+    /// assert_eq!(address, Address::from_8bit(0x81).unwrap());
+    /// ```
+    ///
+    /// # Errors
+    /// This will return `Err` if the byte does not represent a valid address after shifting.
+    /// ```rust
+    /// # use ina219::address::{Address, Pin};
+    ///
+    /// // This is the 7-bit address, so it is out of range as 8-bit address
+    /// assert!(Address::from_8bit(0x40).is_err());
+    /// ```
+    pub const fn from_8bit(byte: u8) -> Result<Self, OutOfRange> {
+        Self::from_byte(byte >> 1)
+    }
+
+    /// Iterate over all 16 valid addresses of the INA219 in ascending order
+    ///
+    /// # Example
+    /// ```rust
+    /// # use ina219::address::Address;
+    ///
+    /// assert_eq!(Address::all().count(), 16);
+    /// assert_eq!(Address::all().next(), Some(Address::default()));
+    /// ```
+    pub fn all() -> impl Iterator<Item = Self> {
+        Self::VALID_ADDRESS.map(|byte| Self { byte })
+    }
+
     /// Get the address as a byte
     #[must_use]
     pub const fn as_byte(self) -> u8 {
@@ -158,6 +244,22 @@ impl Default for Address {
     }
 }
 
+/// Serialized as the 7-bit address byte, see [`Address::as_byte`]
+#[cfg(feature = "serde")]
+impl serde::Serialize for Address {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(self.byte)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Address {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let byte = u8::deserialize(deserializer)?;
+        Self::from_byte(byte).map_err(serde::de::Error::custom)
+    }
+}
+
 /// The given address was not in the expected range for an INA219
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct OutOfRange {
@@ -184,8 +286,7 @@ impl TryFrom<u8> for Address {
     }
 }
 
-#[cfg(feature = "std")]
-impl std::error::Error for OutOfRange {}
+impl core::error::Error for OutOfRange {}
 
 #[cfg(test)]
 mod tests {
@@ -211,6 +312,19 @@ mod tests {
         assert_eq!(bytes, (0b100_0000..=0b100_1111).collect::<Vec<u8>>());
     }
 
+    #[test]
+    fn all_pin_combinations() {
+        let from_pins: Vec<_> = Address::ALL_PIN_COMBINATIONS
+            .into_iter()
+            .map(|(a0, a1)| Address::from_pins(a0, a1))
+            .collect();
+        assert_eq!(from_pins, Address::all().collect::<Vec<_>>());
+
+        for (a0, a1) in Address::ALL_PIN_COMBINATIONS {
+            assert_eq!(Address::from_pins(a0, a1).as_pins(), (a0, a1));
+        }
+    }
+
     #[test]
     fn is_byte_reversible() {
         for byte in 0b100_0000..=0b100_1111 {
@@ -254,4 +368,36 @@ mod tests {
             assert_eq!(a1, a1_);
         }
     }
+
+    #[test]
+    fn all() {
+        let bytes: Vec<u8> = Address::all().map(Address::as_byte).collect();
+        assert_eq!(bytes, (0b100_0000..=0b100_1111).collect::<Vec<u8>>());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_round_trip() {
+        let address = Address::from_pins(Pin::Sda, Pin::Vcc);
+        let json = serde_json::to_string(&address).unwrap();
+        assert_eq!(json, "70");
+        assert_eq!(serde_json::from_str::<Address>(&json).unwrap(), address);
+        assert!(serde_json::from_str::<Address>("42").is_err());
+
+        let json = serde_json::to_string(&Pin::Scl).unwrap();
+        assert_eq!(serde_json::from_str::<Pin>(&json).unwrap(), Pin::Scl);
+    }
+
+    #[test]
+    fn from_8bit() {
+        for byte in 0b100_0000..=0b100_1111 {
+            let address = Address::from_byte(byte).unwrap();
+
+            assert_eq!(Address::from_8bit(byte << 1), Ok(address));
+            assert_eq!(Address::from_8bit((byte << 1) | 1), Ok(address));
+        }
+
+        assert!(Address::from_8bit(0x7F).is_err());
+        assert!(Address::from_8bit(0xA0).is_err());
+    }
 }
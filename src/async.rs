@@ -1,19 +1,30 @@
 use crate::address::Address;
-use crate::calibration::{Calibration, UnCalibrated};
-use crate::configuration::{BusVoltageRange, Configuration, Reset, ShuntVoltageRange};
+use crate::calibration::{
+    Calibration, CalibrationWarning, IntCalibration, MicroAmpere, RawCalibration, UnCalibrated,
+};
+#[cfg(not(feature = "minimal"))]
+use crate::configuration::BusVoltageRange;
+use crate::configuration::{
+    Configuration, MeasuredSignals, OperatingMode, Reset, ShuntVoltageRange,
+};
 use crate::errors::{
     BusVoltageReadError, ConfigurationReadError, InitializationError, InitializationErrorReason,
-    MeasurementError, ShuntVoltageReadError,
+    MeasurementError, ProvisionError, RangeCalibrationError, ShuntVoltageReadError,
 };
 use crate::measurements::{
-    BusVoltage, BusVoltageRegister, CurrentRegister, Measurements, PowerRegister, ShuntVoltage,
-    ShuntVoltageRegister,
+    BusVoltage, BusVoltageRegister, CurrentRegister, Measurements, PowerRegister,
+    SelectedMeasurements, ShuntVoltage, ShuntVoltageRegister, Timestamped,
 };
-use crate::register::WriteRegister;
-use crate::{address, register};
+use crate::register::{ReadRegister, Register, RegisterName, WriteRegister};
+use crate::{address, register, InitOptions};
+use embedded_hal_async::delay::DelayNs;
 use embedded_hal_async::i2c::{I2c, Operation};
 
 /// Embedded HAL compatible driver for the INA219
+///
+/// The driver only ever uses the I2C device through `&mut`, so it works as well with a borrowed
+/// `&mut I2C` or a proxy of a shared bus, e.g. from `embedded-hal-bus` or `embassy-embedded-hal`,
+/// as with an owned bus.
 pub struct INA219<I2C, Calib> {
     i2c: I2C,
     address: address::Address,
@@ -39,6 +50,97 @@ where
     ) -> Result<Self, InitializationError<I2C, I2C::Error>> {
         Self::new_calibrated(i2c, address, UnCalibrated).await
     }
+
+    /// Open an INA219 without calibration, using `delay` to wait while the reset finishes
+    ///
+    /// See [`Self::new_calibrated_with_delay`].
+    ///
+    /// # Errors
+    /// If the device returns an unexpected response a `InitializationError` is returned.
+    pub async fn new_with_delay(
+        i2c: I2C,
+        address: address::Address,
+        delay: &mut impl DelayNs,
+    ) -> Result<Self, InitializationError<I2C, I2C::Error>> {
+        Self::new_calibrated_with_delay(i2c, address, UnCalibrated, delay).await
+    }
+}
+
+impl<I2C> INA219<I2C, RawCalibration>
+where
+    I2C: I2c,
+{
+    /// Write `bits` directly to the calibration register
+    ///
+    /// This is useful to replicate a calibration register value captured from another device.
+    ///
+    /// # Errors
+    /// Returns an error when the underlying I2C device returns an error.
+    pub async fn calibrate_raw(&mut self, bits: u16) -> Result<(), I2C::Error> {
+        self.calibrate(RawCalibration(bits)).await?;
+        Ok(())
+    }
+}
+
+impl<I2C> INA219<I2C, IntCalibration>
+where
+    I2C: I2c,
+{
+    /// Change the shunt voltage range, checking that it fits the calibration
+    ///
+    /// If the largest current that can be measured with the new range is smaller than the largest
+    /// current the calibration can represent, part of the calibration range is clipped. In that
+    /// case [`RangeCalibrationError::RangeClipsCalibration`] is returned and the configuration is
+    /// not changed. All other settings of the configuration are kept.
+    ///
+    /// The current LSB is usually rounded up to a round number (e.g. 100µA for 3.2A in the
+    /// datasheet), which puts the full scale of the calibration slightly above the range. So the
+    /// calibration may go up to 10% above the range before it counts as clipped.
+    ///
+    /// # Errors
+    /// Returns an error if the underlying I2C device returns an error or when the range clips
+    /// the calibration.
+    pub async fn set_shunt_range_checked(
+        &mut self,
+        range: ShuntVoltageRange,
+    ) -> Result<(), RangeCalibrationError<I2C::Error>> {
+        let range_max = self.calib.range_max_current(range);
+        let calibration_max = self.calib.max_current();
+        if range_max.0 * 11 / 10 < calibration_max.0 {
+            return Err(RangeCalibrationError::RangeClipsCalibration {
+                range_max,
+                calibration_max,
+            });
+        }
+
+        let config = self.known_configuration().await?;
+        self.set_configuration(Configuration {
+            shunt_voltage_range: range,
+            ..config
+        })
+        .await?;
+
+        Ok(())
+    }
+
+    /// Read only the last measured current and bus voltage in a single transaction
+    ///
+    /// This skips the shunt voltage and power registers. Unlike [`Self::next_measurement`] this
+    /// does not check whether the values are new or the current calculation overflowed, use the
+    /// flags of the returned [`BusVoltage`] for that.
+    ///
+    /// # Errors
+    /// Returns an error if the underlying I2C device returns an error or when the bus voltage is
+    /// outside of the expected range.
+    pub async fn read_current_and_bus(
+        &mut self,
+    ) -> Result<(MicroAmpere, BusVoltage), MeasurementError<I2C::Error>> {
+        let (current, bus_voltage): (CurrentRegister, _) = self.read2().await?;
+
+        let bus_voltage = self.bus_voltage_from_register(bus_voltage)?;
+
+        Ok((self.calib.current_from_register(current), bus_voltage))
+    }
 }
 
 impl<I2C, Calib> INA219<I2C, Calib>
@@ -54,29 +156,101 @@ where
         i2c: I2C,
         address: address::Address,
         calibration: Calib,
+    ) -> Result<Self, InitializationError<I2C, I2C::Error>> {
+        Self::new_calibrated_with_delay(i2c, address, calibration, &mut NoDelay).await
+    }
+
+    /// Like [`Self::new_calibrated`] but waits using `delay` between the checks whether the reset
+    /// finished
+    ///
+    /// Without a delay the reset is polled as fast as the bus allows, which on a fast bus may give
+    /// up before a slow device finished its reset.
+    ///
+    /// # Errors
+    /// If the device returns an unexpected response a `InitializationError` is returned.
+    pub async fn new_calibrated_with_delay(
+        i2c: I2C,
+        address: address::Address,
+        calibration: Calib,
+        delay: &mut impl DelayNs,
+    ) -> Result<Self, InitializationError<I2C, I2C::Error>> {
+        Self::new_with_options(i2c, address, calibration, delay, InitOptions::DEFAULT).await
+    }
+
+    /// Like [`Self::new_calibrated_with_delay`] but with custom [`InitOptions`]
+    ///
+    /// # Errors
+    /// If the device returns an unexpected response a `InitializationError` is returned.
+    pub async fn new_with_options(
+        i2c: I2C,
+        address: address::Address,
+        calibration: Calib,
+        delay: &mut impl DelayNs,
+        options: InitOptions,
     ) -> Result<Self, InitializationError<I2C, I2C::Error>> {
         let mut new = INA219::new_unchecked(i2c, address, calibration);
 
         // This is done in a function to make error handling easier...
         // since we want to return the device in case something goes wrong
-        match new.init().await {
+        match new.init(delay, options).await {
+            Ok(()) => Ok(new),
+            Err(e) => Err(InitializationError::new(e, new.destroy())),
+        }
+    }
+
+    /// Open an INA219 that is already running without resetting it and apply the calibration
+    ///
+    /// Unlike [`Self::new_calibrated`] the configuration is left as it is, so ongoing conversions
+    /// are not disturbed. This allows to join a running measurement session, e.g. as a watchdog.
+    /// If paranoid the configuration is read and the voltages are checked to be in the configured
+    /// ranges. A calibration of 0, like [`UnCalibrated`], is not written so the calibration of
+    /// the device is kept as well.
+    ///
+    /// # Errors
+    /// If the device returns an unexpected response a `InitializationError` is returned.
+    pub async fn new_no_reset(
+        i2c: I2C,
+        address: address::Address,
+        calibration: Calib,
+    ) -> Result<Self, InitializationError<I2C, I2C::Error>> {
+        let mut new = INA219::new_unchecked(i2c, address, calibration);
+
+        match new.attach().await {
             Ok(()) => Ok(new),
             Err(e) => Err(InitializationError::new(e, new.destroy())),
         }
     }
 
+    /// Check the state of a running device and apply the register value from self.calib
+    async fn attach(&mut self) -> Result<(), InitializationErrorReason<I2C::Error>> {
+        #[cfg(feature = "paranoid")]
+        {
+            self.config = Some(self.read().await?);
+
+            // Check that the voltages are in the ranges of the current configuration
+            self.shunt_voltage().await?;
+            self.bus_voltage().await?;
+        }
+
+        self.write_initial_calibration().await?;
+        Ok(())
+    }
+
     /// Perform the following steps on this device to bring it into a known state
     /// - Perform a Reset
-    /// - Wait for the Reset to finish, by polling 10 times for if it is already done (are we there yet?)
+    /// - Wait for the Reset to finish, by polling `reset_retries` times for if it is already done (are we there yet?)
     /// - If paranoid: Check if all registers are in the expected ranges
     /// - Apply the register value from self.calib
-    async fn init(&mut self) -> Result<(), InitializationErrorReason<I2C::Error>> {
-        self.reset().await?;
+    async fn init(
+        &mut self,
+        delay: &mut impl DelayNs,
+        options: InitOptions,
+    ) -> Result<(), InitializationErrorReason<I2C::Error>> {
+        self.reset(delay, options.reset_retries).await?;
 
         // If we are paranoid we perform extra checks to verify we talk to a real INA219
         #[cfg(feature = "paranoid")]
         {
-            use crate::calibration::RawCalibration;
             use crate::register::RegisterName;
 
             // read_configuration before should have populated the config which can now be used to
@@ -110,6 +284,13 @@ where
         }
 
         // Calibrate the device
+        self.write_initial_calibration().await?;
+
+        Ok(())
+    }
+
+    /// Write self.calib to the device unless it is 0
+    async fn write_initial_calibration(&mut self) -> Result<(), I2C::Error> {
         let bits = self.calib.register_bits();
         if bits == 0 {
             // Do nothing
@@ -137,16 +318,45 @@ where
     /// Destroy the driver returning the underlying I2C device
     ///
     /// This does leave the device in it's current state.
+    ///
+    /// All methods reading or writing the device borrow the driver, so after an I2C error the
+    /// device can still be recovered with this or [`Self::release`]. For example to reset the bus
+    /// and open the INA219 again with the same calibration.
     pub fn destroy(self) -> I2C {
         self.i2c
     }
 
+    /// Destroy the driver returning the underlying I2C device and the calibration
+    ///
+    /// Like [`Self::destroy`], but also returns the calibration so the driver can be reconstructed
+    /// with [`Self::new_calibrated`] or [`Self::new_unchecked`].
+    pub fn release(self) -> (I2C, Calib) {
+        (self.i2c, self.calib)
+    }
+
+    /// Replace the I2C device by the result of `f` keeping all other state of the driver
+    #[allow(dead_code)] // Not every driver flavour wraps its bus
+    pub(crate) fn map_i2c<I2C2>(self, f: impl FnOnce(I2C) -> I2C2) -> INA219<I2C2, Calib> {
+        INA219 {
+            i2c: f(self.i2c),
+            address: self.address,
+            #[cfg(feature = "paranoid")]
+            config: self.config,
+            calib: self.calib,
+        }
+    }
+
     /// Perform a power-on-reset
     ///
     /// Make sure to set calibration after this finishes so self.calib matches what the device is
     /// calibrated to
-    async fn reset(&mut self) -> Result<(), InitializationErrorReason<I2C::Error>> {
-        const MAX_RESET_READ_RETRIES: u8 = 10;
+    async fn reset(
+        &mut self,
+        delay: &mut impl DelayNs,
+        max_retries: u8,
+    ) -> Result<(), InitializationErrorReason<I2C::Error>> {
+        /// Time between two checks whether the reset finished
+        const RESET_POLL_INTERVAL_US: u32 = 200;
 
         // Set the reset bit
         self.set_configuration(Configuration {
@@ -155,11 +365,6 @@ where
         })
         .await?;
 
-        #[cfg(feature = "paranoid")]
-        {
-            self.config = None; // Reset is actually never read back, so it does not make sense to store it.
-        }
-
         // Wait until the device reports that it is done
         let mut attempt = 0;
         loop {
@@ -171,10 +376,11 @@ where
                 return Ok(());
             }
 
-            if attempt > MAX_RESET_READ_RETRIES {
+            if attempt >= max_retries {
                 return Err(InitializationErrorReason::ConfigurationNotDefaultAfterReset);
             }
 
+            delay.delay_us(RESET_POLL_INTERVAL_US).await;
             attempt += 1;
         }
     }
@@ -182,7 +388,7 @@ where
     /// Read the current [`Configuration`]
     ///
     /// # Errors
-    /// Returns Err() when the underlying I2C device returns an error.
+    /// Returns an error when the underlying I2C device returns an error.
     ///
     /// *With feature `paranoid`*:
     ///
@@ -196,7 +402,7 @@ where
         #[cfg(feature = "paranoid")]
         {
             let saved = *self.config.get_or_insert(read);
-            if read != saved {
+            if !read.semantically_eq(saved) {
                 self.config = Some(read);
                 return Err(ConfigurationReadError::ConfigurationMismatch { read, saved });
             }
@@ -205,25 +411,141 @@ where
         Ok(read)
     }
 
+    /// Read the raw bits of the configuration register
+    ///
+    /// Unlike [`Self::configuration`] this does not decode the bits and does not compare them to
+    /// the saved configuration. This is meant for tight polling loops that only check a few bits.
+    ///
+    /// # Errors
+    /// Returns an error when the underlying I2C device returns an error.
+    pub async fn configuration_bits(&mut self) -> Result<u16, I2C::Error> {
+        self.read_bits(Configuration::ADDRESS).await
+    }
+
+    /// The configuration the driver believes the device to have, without reading it
+    ///
+    /// This is `None` if the configuration is not known, e.g. after a failed write. Use
+    /// [`Self::configuration`] to read it from the device in that case.
+    ///
+    /// Without feature `paranoid` the driver does not keep track of the configuration and this
+    /// always returns `None`.
+    #[cfg(feature = "paranoid")]
+    pub const fn cached_configuration(&self) -> Option<Configuration> {
+        self.config
+    }
+
+    /// The configuration the driver believes the device to have, without reading it
+    ///
+    /// Without feature `paranoid` the driver does not keep track of the configuration and this
+    /// always returns `None`.
+    #[cfg(not(feature = "paranoid"))]
+    #[allow(clippy::unused_self)]
+    pub const fn cached_configuration(&self) -> Option<Configuration> {
+        None
+    }
+
+    /// Set the configuration the driver believes the device to have, without reading or writing it
+    ///
+    /// Methods that only need to know the configuration, like [`Self::measure`], use this instead
+    /// of reading the configuration register. [`Self::configuration`] still reads the device and
+    /// returns [`ConfigurationReadError::ConfigurationMismatch`] if it differs from `conf`, after
+    /// which the read value is trusted instead.
+    ///
+    /// Without feature `paranoid` the driver does not keep track of the configuration and this
+    /// does nothing.
+    #[cfg(feature = "paranoid")]
+    pub const fn trust_configuration(&mut self, conf: Configuration) {
+        self.config = Some(conf);
+    }
+
+    /// Set the configuration the driver believes the device to have, without reading or writing it
+    ///
+    /// Without feature `paranoid` the driver does not keep track of the configuration and this
+    /// does nothing.
+    #[cfg(not(feature = "paranoid"))]
+    #[allow(clippy::unused_self)]
+    pub const fn trust_configuration(&mut self, _conf: Configuration) {}
+
+    /// Time until the next conversion completes, `elapsed` after the configuration was written
+    ///
+    /// This uses the [`Self::cached_configuration`] to schedule reads instead of polling, see
+    /// [`Configuration::time_until_ready_us`]. Returns `None` if the configuration is not known,
+    /// which is always the case without feature `paranoid`, or the device is not converting.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn time_until_ready(&self, elapsed: std::time::Duration) -> Option<std::time::Duration> {
+        self.cached_configuration()?.time_until_ready(elapsed)
+    }
+
+    /// Read the raw value of the calibration register
+    ///
+    /// This can be used to check the device still holds the calibration that was written, e.g.
+    /// after a brownout. For an [`IntCalibration`] compare it to
+    /// [`Calibration::register_bits`], or turn it back into a calibration with
+    /// [`IntCalibration::from_bits`].
+    ///
+    /// # Errors
+    /// Returns an error when the underlying I2C device returns an error.
+    pub async fn read_calibration(&mut self) -> Result<u16, I2C::Error> {
+        let RawCalibration(bits) = self.read().await?;
+        Ok(bits)
+    }
+
+    /// Best-effort fingerprint of the device for logging
+    ///
+    /// The INA219 has no ID register, so this combines the address with the contents of the
+    /// configuration and calibration register into a single value. Devices with the same address
+    /// and settings have the same fingerprint. But it can help to tell devices apart in logs and
+    /// to notice when a device was swapped or lost its settings.
+    ///
+    /// # Errors
+    /// Returns an error when the underlying I2C device returns an error.
+    pub async fn fingerprint(&mut self) -> Result<u32, I2C::Error> {
+        // FNV-1a, which is simple and good enough to tell a few devices apart
+        const OFFSET_BASIS: u32 = 0x811c_9dc5;
+        const PRIME: u32 = 0x0100_0193;
+
+        let config = self.configuration_bits().await?.to_be_bytes();
+        let calib = self.read::<RawCalibration>().await?.0.to_be_bytes();
+
+        let mut hash = OFFSET_BASIS;
+        for byte in [
+            self.address.as_byte(),
+            config[0],
+            config[1],
+            calib[0],
+            calib[1],
+        ] {
+            hash ^= u32::from(byte);
+            hash = hash.wrapping_mul(PRIME);
+        }
+
+        Ok(hash)
+    }
+
     /// Set a new [`Configuration`]
     ///
+    /// If `conf.reset` is [`Reset::Reset`] the device performs a power-on-reset instead of
+    /// applying the other settings. All registers return to their defaults, including the
+    /// calibration, so call [`Self::calibrate`] again afterwards. The driver forgets the saved
+    /// configuration, it is read from the device again when it is needed.
+    ///
     /// # Errors
-    /// Returns Err() when the underlying I2C device returns an error.
+    /// Returns an error when the underlying I2C device returns an error.
     pub async fn set_configuration(&mut self, conf: Configuration) -> Result<(), I2C::Error> {
         let result = self.write(conf).await;
 
-        // TODO what to do in case this causes a reset? Just panic?
-
         #[cfg(feature = "paranoid")]
         {
             self.config = match result {
+                // The device is back at its defaults which are not what we have written
+                Ok(()) if conf.reset == Reset::Reset => None,
                 Ok(()) => Some(conf),
                 // We don't know anything about the current conf
                 Err(_) => None,
             };
         }
 
-        #[cfg_attr(not(feature = "paranoid"), allow(clippy::let_and_return))]
         result
     }
 
@@ -235,36 +557,318 @@ where
     /// # Errors
     /// Returns an error if the underlying I2C device returned an error.
     pub async fn trigger(&mut self) -> Result<(), I2C::Error> {
-        let config = {
-            #[cfg(feature = "paranoid")]
-            {
-                self.config
+        let old_config = self.known_configuration().await?;
+        self.set_configuration(old_config).await
+    }
+
+    /// Trigger a new measurement and return it once the conversion is done
+    ///
+    /// This combines [`Self::trigger`] and [`Self::measure`], so it waits for the conversion time
+    /// of the configuration and polls a little longer if needed. Only useful if the device is in
+    /// triggered mode, in continuous mode this just waits for the next conversion.
+    ///
+    /// # Errors
+    /// Returns an error if the underlying I2C device returns an error, when any of the
+    /// measurements is outside of their expected ranges, when the device is not configured to
+    /// perform conversions or when the conversion takes too long.
+    #[allow(clippy::type_complexity)] // FIXME: Find a more elegant type
+    pub async fn trigger_and_read(
+        &mut self,
+        delay: &mut impl DelayNs,
+    ) -> Result<Measurements<Calib::Current, Calib::Power>, MeasurementError<I2C::Error>> {
+        self.trigger().await?;
+        self.measure(delay).await
+    }
+
+    /// Change only the [`OperatingMode`] keeping all other settings of the configuration
+    ///
+    /// *With feature `paranoid`*: The saved configuration is used instead of reading it from the
+    /// device first.
+    ///
+    /// # Errors
+    /// Returns an error if the underlying I2C device returned an error.
+    pub async fn set_operating_mode(&mut self, mode: OperatingMode) -> Result<(), I2C::Error> {
+        let config = self.known_configuration().await?;
+        self.set_configuration(Configuration {
+            operating_mode: mode,
+            ..config
+        })
+        .await
+    }
+
+    /// Put the device into [`OperatingMode::PowerDown`] to reduce its power usage
+    ///
+    /// Returns the operating mode that was active before, pass it to [`Self::power_up`] to resume.
+    /// If the device already was powered down that is [`OperatingMode::PowerDown`] itself.
+    ///
+    /// # Errors
+    /// Returns an error if the underlying I2C device returned an error.
+    pub async fn power_down(&mut self) -> Result<OperatingMode, I2C::Error> {
+        let config = self.known_configuration().await?;
+
+        self.set_configuration(Configuration {
+            operating_mode: OperatingMode::PowerDown,
+            ..config
+        })
+        .await?;
+
+        Ok(config.operating_mode)
+    }
+
+    /// Leave [`OperatingMode::PowerDown`] by switching to `mode`
+    ///
+    /// Pass the mode returned by [`Self::power_down`], or [`OperatingMode::default`] if it is not
+    /// known. If the device is not powered down this does nothing.
+    ///
+    /// The conversion ready flag is undefined after power down until the first new conversion
+    /// finishes. To keep [`Self::next_measurement`] from returning a measurement from before the
+    /// power down as new, the flag is cleared by reading the power register after resuming.
+    ///
+    /// **Note:** The device needs 40µs to recover from power down before it starts measuring.
+    ///
+    /// # Errors
+    /// Returns an error if the underlying I2C device returned an error.
+    pub async fn power_up(&mut self, mode: OperatingMode) -> Result<(), I2C::Error> {
+        let config = self.known_configuration().await?;
+        if config.operating_mode != OperatingMode::PowerDown {
+            return Ok(());
+        }
+
+        self.set_configuration(Configuration {
+            operating_mode: mode,
+            ..config
+        })
+        .await?;
+
+        // Reading the power register clears the conversion ready flag
+        self.read::<PowerRegister>().await?;
+
+        Ok(())
+    }
+
+    /// Wait until a new measurement is ready and return it
+    ///
+    /// The conversion ready flag is polled every `poll_interval_us` µs. This avoids both sleeping
+    /// longer than needed and keeping the bus busy, which matters for long averaging conversions.
+    ///
+    /// If no measurement arrives within twice the conversion time of the configuration
+    /// [`MeasurementError::ConversionTimeout`] is returned.
+    ///
+    /// # Errors
+    /// Returns an error if the underlying I2C device returns an error, when any of the
+    /// measurements is outside of their expected ranges, when the device is not configured to
+    /// perform conversions or when the conversion takes too long.
+    #[allow(clippy::type_complexity)] // FIXME: Find a more elegant type
+    pub async fn await_conversion(
+        &mut self,
+        delay: &mut impl DelayNs,
+        poll_interval_us: u32,
+    ) -> Result<Measurements<Calib::Current, Calib::Power>, MeasurementError<I2C::Error>> {
+        let conversion_time_us = self.active_conversion_time_us().await?;
+
+        let max_wait_us = conversion_time_us.saturating_mul(2);
+        let poll_interval_us = poll_interval_us.max(1);
+        let mut waited_us: u32 = 0;
+
+        loop {
+            if self.is_conversion_ready().await? {
+                if let Some(measurements) = self.next_measurement().await? {
+                    return Ok(measurements);
+                }
             }
-            #[cfg(not(feature = "paranoid"))]
-            {
-                None
+
+            if waited_us >= max_wait_us {
+                return Err(MeasurementError::ConversionTimeout);
             }
-        };
 
-        let old_config = match config {
-            None => match self.configuration().await {
-                Ok(c) => c,
-                Err(ConfigurationReadError::I2cError(e)) => return Err(e),
-                Err(ConfigurationReadError::ConfigurationMismatch { .. }) => unreachable!("This can only happen if we are paranoid and have stored a configuration. But in that case we never perform a read!"),
-            },
-            Some(c) => c,
-        };
+            delay.delay_us(poll_interval_us).await;
+            waited_us = waited_us.saturating_add(poll_interval_us);
+        }
+    }
 
-        self.set_configuration(old_config).await
+    /// Wait for the conversion time of the configuration and return the new measurement
+    ///
+    /// If the measurement is not ready after the conversion time, it is read again after a short
+    /// backoff until twice the conversion time passed. After that
+    /// [`MeasurementError::ConversionTimeout`] is returned. For devices in triggered mode call
+    /// [`Self::trigger`] first.
+    ///
+    /// # Errors
+    /// Returns an error if the underlying I2C device returns an error, when any of the
+    /// measurements is outside of their expected ranges, when the device is not configured to
+    /// perform conversions or when the conversion takes too long.
+    #[allow(clippy::type_complexity)] // FIXME: Find a more elegant type
+    pub async fn measure(
+        &mut self,
+        delay: &mut impl DelayNs,
+    ) -> Result<Measurements<Calib::Current, Calib::Power>, MeasurementError<I2C::Error>> {
+        /// How often the measurement is retried during one conversion time
+        const RETRIES_PER_CONVERSION: u32 = 8;
+
+        let conversion_time_us = self.active_conversion_time_us().await?;
+        let backoff_us = (conversion_time_us / RETRIES_PER_CONVERSION).max(1);
+
+        delay.delay_us(conversion_time_us).await;
+        let mut waited_us = conversion_time_us;
+
+        loop {
+            if let Some(measurements) = self.next_measurement().await? {
+                return Ok(measurements);
+            }
+
+            if waited_us >= conversion_time_us.saturating_mul(2) {
+                return Err(MeasurementError::ConversionTimeout);
+            }
+
+            delay.delay_us(backoff_us).await;
+            waited_us = waited_us.saturating_add(backoff_us);
+        }
+    }
+
+    /// Read the next measurement if `ready` returns true
+    ///
+    /// The INA219 has no interrupt pin, so this lets an external source like a timer or RTOS flag
+    /// decide when to read. `ready` is called once, if it returns false `Ok(None)` is returned
+    /// without touching the bus. Otherwise the conversion ready flag of the device is checked to
+    /// make sure the measurement is actually new, just like [`Self::next_measurement`].
+    ///
+    /// # Errors
+    /// Returns an error if the underlying I2C device returns an error or when any of the
+    /// measurements is outside of their expected ranges.
+    #[allow(clippy::type_complexity)] // FIXME: Find a more elegant type
+    pub async fn read_when<F: FnOnce() -> bool>(
+        &mut self,
+        ready: F,
+    ) -> Result<Option<Measurements<Calib::Current, Calib::Power>>, MeasurementError<I2C::Error>>
+    {
+        if !ready() {
+            return Ok(None);
+        }
+
+        self.next_measurement().await
+    }
+
+    /// Conversion time of the known configuration, or an error if the device does not convert
+    async fn active_conversion_time_us(&mut self) -> Result<u32, MeasurementError<I2C::Error>> {
+        self.known_configuration()
+            .await?
+            .conversion_time_us()
+            .ok_or(MeasurementError::DeviceNotConverting)
+    }
+
+    /// Return the saved configuration, or read it from the device if there is none
+    async fn known_configuration(&mut self) -> Result<Configuration, I2C::Error> {
+        #[cfg(feature = "paranoid")]
+        if let Some(config) = self.config {
+            return Ok(config);
+        }
+
+        self.read().await
     }
 
     /// Set a new [`Calibration`]
     ///
+    /// **Note:** The current and power registers are only updated by a new conversion. So if the
+    /// device is powered down they keep returning stale values until it resumes. See
+    /// [`Self::calibrate_checked`] to detect this.
+    ///
+    /// Returns the value written to the calibration register. For an [`IntCalibration`] the
+    /// register value is rounded, [`IntCalibration::effective_current_lsb`] gives the current LSB
+    /// it actually results in.
+    ///
     /// # Errors
-    /// Returns Err() when the underlying I2C device returns an error.
-    pub async fn calibrate(&mut self, value: Calib) -> Result<(), I2C::Error> {
+    /// Returns an error when the underlying I2C device returns an error.
+    pub async fn calibrate(&mut self, value: Calib) -> Result<u16, I2C::Error> {
         self.calib = value;
-        write(&mut self.i2c, self.address, &self.calib).await
+        write(&mut self.i2c, self.address, &self.calib).await?;
+        Ok(self.calib.register_bits())
+    }
+
+    /// Change the configuration and calibration together
+    ///
+    /// The configuration is written first, then the calibration. Afterwards the power register is
+    /// read to clear the conversion ready flag. A conversion that finishes between the two writes
+    /// may still use the old calibration, but because of the cleared flag
+    /// [`Self::next_measurement`] only returns conversions that finished after both were written.
+    /// The saved configuration and calibration of the driver are updated as well.
+    ///
+    /// # Errors
+    /// Returns an error when the underlying I2C device returns an error. In that case the state of
+    /// the device is unknown and this should be retried.
+    pub async fn reconfigure(
+        &mut self,
+        conf: Configuration,
+        calib: Calib,
+    ) -> Result<(), I2C::Error> {
+        self.set_configuration(conf).await?;
+        self.calibrate(calib).await?;
+
+        // Reading the power register clears the conversion ready flag
+        self.read::<PowerRegister>().await?;
+
+        Ok(())
+    }
+
+    /// Write both calibration and configuration and verify the device took them
+    ///
+    /// The calibration is written first, then the configuration. Afterwards both registers are
+    /// read back and compared to the written values. This is meant as the robust setup path, e.g.
+    /// when provisioning devices. Do not set [`Reset::Reset`] in `config`, the reset would discard
+    /// both values again.
+    ///
+    /// # Errors
+    /// Returns an error when the underlying I2C device returns an error or when one of the
+    /// registers does not hold the written value, reporting which one.
+    pub async fn provision(
+        &mut self,
+        config: Configuration,
+        calib: Calib,
+    ) -> Result<(), ProvisionError<I2C::Error>> {
+        self.calibrate(calib).await?;
+        self.set_configuration(config).await?;
+
+        let read: Configuration = self.read().await?;
+        if !read.semantically_eq(config) {
+            #[cfg(feature = "paranoid")]
+            {
+                self.config = Some(read);
+            }
+            return Err(ProvisionError::ConfigurationMismatch {
+                read,
+                written: config,
+            });
+        }
+
+        let RawCalibration(read) = self.read().await?;
+        let written = self.calib.register_bits();
+        if read != written {
+            return Err(ProvisionError::CalibrationMismatch { read, written });
+        }
+
+        Ok(())
+    }
+
+    /// Set a new [`Calibration`] and warn if the device is not converting
+    ///
+    /// The calibration is always written. But if the device is in [`OperatingMode::PowerDown`] or
+    /// [`OperatingMode::AdcOff`] a [`CalibrationWarning`] is returned, since the current and power
+    /// registers will not reflect the new calibration until a conversion was performed.
+    ///
+    /// # Errors
+    /// Returns an error when the underlying I2C device returns an error.
+    pub async fn calibrate_checked(
+        &mut self,
+        value: Calib,
+    ) -> Result<Option<CalibrationWarning>, I2C::Error> {
+        let mode = self.known_configuration().await?.operating_mode;
+        self.calibrate(value).await?;
+
+        Ok(match mode {
+            OperatingMode::PowerDown | OperatingMode::AdcOff => {
+                Some(CalibrationWarning::DeviceNotConverting(mode))
+            }
+            OperatingMode::Triggered(_) | OperatingMode::Continous(_) => None,
+        })
     }
 
     /// Checks if a new measurement was performed since the last configuration change,
@@ -272,14 +876,229 @@ where
     ///
     /// TODO: Explain caveats around resetting the conversion ready flag
     ///
+    /// If there is no new data and the device is in [`OperatingMode::AdcOff`] or
+    /// [`OperatingMode::PowerDown`], no new data will ever arrive. So instead of `Ok(None)`
+    /// [`MeasurementError::DeviceNotConverting`] is returned to prevent polling forever. This is
+    /// only detected with the `paranoid` feature, which keeps track of the configuration. Without
+    /// it `Ok(None)` is returned, [`Self::measure`] and [`Self::await_conversion`] still detect it
+    /// before they start waiting.
+    ///
     /// # Errors
-    /// Returns an error if the underlying I2C device returns an error or when any of the
-    /// measurements is outside of their expected ranges.
+    /// Returns an error if the underlying I2C device returns an error, when any of the
+    /// measurements is outside of their expected ranges or when the device is not configured to
+    /// perform conversions.
     #[allow(clippy::type_complexity)] // FIXME: Find a more elegant type
     pub async fn next_measurement(
         &mut self,
     ) -> Result<Option<Measurements<Calib::Current, Calib::Power>>, MeasurementError<I2C::Error>>
     {
+        let (bus_voltage, power, shunt_voltage, current) =
+            self.read_measurement_registers().await?;
+
+        if !bus_voltage.is_conversion_ready() {
+            // Only the saved configuration is checked, reading it would slow down every poll
+            let not_converting = self
+                .cached_configuration()
+                .is_some_and(|config| config.conversion_time_us().is_none());
+            if not_converting {
+                return Err(MeasurementError::DeviceNotConverting);
+            }
+
+            // No new data... nothing to do...
+            return Ok(None);
+        }
+
+        self.measurements_from_registers(bus_voltage, power, shunt_voltage, current)
+            .map(Some)
+    }
+
+    /// Like [`Self::next_measurement`] but tags the measurement with the time returned by `now`
+    ///
+    /// `now` is only called if there is a new measurement, directly after the registers were read.
+    ///
+    /// # Errors
+    /// Same as [`Self::next_measurement`].
+    #[allow(clippy::type_complexity)] // FIXME: Find a more elegant type
+    pub async fn next_measurement_at<T>(
+        &mut self,
+        now: impl FnOnce() -> T,
+    ) -> Result<
+        Option<Timestamped<T, Measurements<Calib::Current, Calib::Power>>>,
+        MeasurementError<I2C::Error>,
+    > {
+        let measurement = self.next_measurement().await?;
+        Ok(measurement.map(|value| Timestamped {
+            timestamp: now(),
+            value,
+        }))
+    }
+
+    /// Read the last measurement, even if no new conversion was performed since the last read
+    ///
+    /// Like [`Self::next_measurement`] but instead of returning `Ok(None)` when there is no new
+    /// data, the last measured values are returned. The returned `bool` is the conversion ready
+    /// flag: `true` if the measurement is new, `false` if it was already seen.
+    ///
+    /// # Errors
+    /// Returns an error if the underlying I2C device returns an error or when any of the
+    /// measurements is outside of their expected ranges.
+    #[allow(clippy::type_complexity)] // FIXME: Find a more elegant type
+    pub async fn next_measurement_allow_stale(
+        &mut self,
+    ) -> Result<(Measurements<Calib::Current, Calib::Power>, bool), MeasurementError<I2C::Error>>
+    {
+        let (bus_voltage, power, shunt_voltage, current) =
+            self.read_measurement_registers().await?;
+
+        let is_fresh = bus_voltage.is_conversion_ready();
+        let measurements =
+            self.measurements_from_registers(bus_voltage, power, shunt_voltage, current)?;
+
+        Ok((measurements, is_fresh))
+    }
+
+    /// Read the new measurement without clearing the conversion ready flag
+    ///
+    /// Reading the power register clears the conversion ready flag, so the power is not read and
+    /// [`Measurements::power`] is left empty. Calling this repeatedly returns the same conversion
+    /// until the device finishes the next one, which is useful in triggered mode to read the
+    /// results more than once. In continuous mode [`Self::next_measurement`] is usually the better
+    /// choice, since without clearing the flag a repeated conversion cannot be told apart from a
+    /// new one.
+    ///
+    /// # Errors
+    /// Returns an error if the underlying I2C device returns an error or when any of the
+    /// measurements is outside of their expected ranges.
+    #[allow(clippy::type_complexity)] // FIXME: Find a more elegant type
+    pub async fn next_measurement_peek(
+        &mut self,
+    ) -> Result<Option<Measurements<Calib::Current, ()>>, MeasurementError<I2C::Error>> {
+        let (bus_voltage, shunt_voltage, current) = if Calib::READ_CURRENT {
+            self.read3().await?
+        } else {
+            let (bus_voltage, shunt_voltage) = self.read2().await?;
+            (bus_voltage, shunt_voltage, CurrentRegister(0))
+        };
+
+        let bus_voltage = self.bus_voltage_from_register(bus_voltage)?;
+        if !bus_voltage.is_conversion_ready() {
+            return Ok(None);
+        }
+
+        let shunt_voltage = self.shunt_voltage_from_register(shunt_voltage)?;
+        if bus_voltage.has_math_overflowed() {
+            return Err(MeasurementError::MathOverflow(Measurements {
+                bus_voltage,
+                shunt_voltage,
+                current: (),
+                power: (),
+            }));
+        }
+
+        Ok(Some(Measurements {
+            bus_voltage,
+            shunt_voltage,
+            current: self.calib.current_from_register(current),
+            power: (),
+        }))
+    }
+
+    /// Read the raw contents of all measurement registers without any checks
+    ///
+    /// Unlike [`Self::next_measurement`] no range checks are performed, and neither the conversion
+    /// ready nor the math overflow flag is evaluated, they can be checked on the returned
+    /// [`BusVoltage`]. Like [`Self::next_measurement`] this reads the power register and so clears
+    /// the conversion ready flag.
+    ///
+    /// # Errors
+    /// Returns an error if the underlying I2C device returns an error.
+    pub async fn read_raw_measurements(
+        &mut self,
+    ) -> Result<Measurements<CurrentRegister, PowerRegister>, I2C::Error> {
+        let (bus_voltage, power, shunt_voltage, current) = self.read4().await?;
+
+        Ok(Measurements {
+            bus_voltage: BusVoltage::from_bits_unchecked(bus_voltage),
+            shunt_voltage: ShuntVoltage::from_bits_unchecked(shunt_voltage),
+            current,
+            power,
+        })
+    }
+
+    /// Read only the selected measurement registers
+    ///
+    /// The voltages in `which` are read, as well as the current and power if requested. This
+    /// reduces the traffic on the bus if not all values are needed. The registers are read in a
+    /// single transaction unless feature `no_transaction` is enabled. The power register is read
+    /// last, since reading it clears the conversion ready flag of the bus voltage.
+    ///
+    /// Like [`Self::read_raw_measurements`] the conversion ready and math overflow flags are not
+    /// evaluated, but the voltages are checked to be in range.
+    ///
+    /// # Errors
+    /// Returns an error if the underlying I2C device returns an error or when any of the read
+    /// voltages is outside of their expected ranges.
+    #[allow(clippy::type_complexity)] // FIXME: Find a more elegant type
+    pub async fn read_selected(
+        &mut self,
+        which: MeasuredSignals,
+        current: bool,
+        power: bool,
+    ) -> Result<SelectedMeasurements<Calib::Current, Calib::Power>, MeasurementError<I2C::Error>>
+    {
+        let selected = [
+            (RegisterName::BusVoltage, which.includes_bus_voltage()),
+            (RegisterName::ShuntVoltage, which.includes_shunt_voltage()),
+            (RegisterName::Current, current),
+            (RegisterName::Power, power),
+        ];
+        let mut regs = [RegisterName::BusVoltage; 4];
+        let mut count = 0;
+        for (reg, _) in selected.iter().filter(|(_, is_selected)| *is_selected) {
+            regs[count] = *reg;
+            count += 1;
+        }
+
+        let mut buf = [0u8; 8];
+        self.read_into(&regs[..count], &mut buf[..count * 2])
+            .await?;
+
+        let mut values = buf
+            .chunks_exact(2)
+            .map(|chunk| u16::from_be_bytes([chunk[0], chunk[1]]));
+        let mut next_if = |is_selected: bool| if is_selected { values.next() } else { None };
+
+        let bus_voltage = next_if(which.includes_bus_voltage())
+            .map(|bits| self.bus_voltage_from_register(BusVoltageRegister::from_bits(bits)))
+            .transpose()?;
+        let shunt_voltage = next_if(which.includes_shunt_voltage())
+            .map(|bits| self.shunt_voltage_from_register(ShuntVoltageRegister::from_bits(bits)))
+            .transpose()?;
+        let current =
+            next_if(current).map(|bits| self.calib.current_from_register(CurrentRegister(bits)));
+        let power = next_if(power).map(|bits| self.calib.power_from_register(PowerRegister(bits)));
+
+        Ok(SelectedMeasurements {
+            bus_voltage,
+            shunt_voltage,
+            current,
+            power,
+        })
+    }
+
+    /// Read all registers needed for a measurement, the bus voltage is checked to be in range
+    #[allow(clippy::type_complexity)]
+    async fn read_measurement_registers(
+        &mut self,
+    ) -> Result<
+        (
+            BusVoltage,
+            PowerRegister,
+            ShuntVoltageRegister,
+            CurrentRegister,
+        ),
+        MeasurementError<I2C::Error>,
+    > {
         let (bus_voltage, power, shunt_voltage, current) = if Calib::READ_CURRENT {
             self.read4().await?
         } else {
@@ -288,11 +1107,18 @@ where
         };
 
         let bus_voltage = self.bus_voltage_from_register(bus_voltage)?;
-        if !bus_voltage.is_conversion_ready() {
-            // No new data... nothing to do...
-            return Ok(None);
-        }
 
+        Ok((bus_voltage, power, shunt_voltage, current))
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn measurements_from_registers(
+        &mut self,
+        bus_voltage: BusVoltage,
+        power: PowerRegister,
+        shunt_voltage: ShuntVoltageRegister,
+        current: CurrentRegister,
+    ) -> Result<Measurements<Calib::Current, Calib::Power>, MeasurementError<I2C::Error>> {
         let shunt_voltage = self.shunt_voltage_from_register(shunt_voltage)?;
 
         if bus_voltage.has_math_overflowed() {
@@ -304,12 +1130,12 @@ where
             }));
         }
 
-        Ok(Some(Measurements {
+        Ok(Measurements {
             bus_voltage,
             shunt_voltage,
             current: self.calib.current_from_register(current),
             power: self.calib.power_from_register(power),
-        }))
+        })
     }
 
     /// Read the last measured shunt voltage
@@ -325,6 +1151,39 @@ where
         self.shunt_voltage_from_register(value)
     }
 
+    /// Read the last measured shunt voltage, clamping it to the expected range
+    ///
+    /// Like [`Self::shunt_voltage`], but a shunt voltage outside of the expected range, e.g. due
+    /// to a transient spike, is limited to the full scale of that range using
+    /// [`ShuntVoltage::clamp_to_range`] instead of returning an error.
+    ///
+    /// # Errors
+    /// Returns an error if the underlying I2C device returns an error.
+    pub async fn shunt_voltage_clamped(&mut self) -> Result<ShuntVoltage, I2C::Error> {
+        match self.shunt_voltage().await {
+            Ok(shunt_voltage) => Ok(shunt_voltage),
+            Err(ShuntVoltageReadError::ShuntVoltageOutOfRange { should, is }) => {
+                Ok(is.clamp_to_range(should))
+            }
+            Err(ShuntVoltageReadError::I2cError(err)) => Err(err),
+        }
+    }
+
+    #[cfg(feature = "minimal")]
+    #[allow(
+        clippy::unused_self,
+        clippy::unnecessary_wraps,
+        clippy::missing_const_for_fn
+    )]
+    fn shunt_voltage_from_register(
+        &mut self,
+        value: ShuntVoltageRegister,
+    ) -> Result<ShuntVoltage, ShuntVoltageReadError<I2C::Error>> {
+        // Minimal builds trust the device and skip the range check
+        Ok(ShuntVoltage::from_bits_unchecked(value))
+    }
+
+    #[cfg(not(feature = "minimal"))]
     #[cfg_attr(not(feature = "paranoid"), allow(clippy::unused_self))]
     fn shunt_voltage_from_register(
         &mut self,
@@ -359,6 +1218,34 @@ where
         self.bus_voltage_from_register(value)
     }
 
+    /// Check if a new measurement is available without clearing the conversion ready flag
+    ///
+    /// Only the bus voltage register is read. Unlike [`Self::next_measurement`], which reads the
+    /// power register and thereby clears the flag, calling this does not change the state of the
+    /// device. So a following [`Self::next_measurement`] still returns the new measurement.
+    ///
+    /// # Errors
+    /// Returns an error if the underlying I2C device returns an error or when the bus voltage
+    /// is outside of the expected range given in the last written configuration.
+    pub async fn is_conversion_ready(&mut self) -> Result<bool, BusVoltageReadError<I2C::Error>> {
+        Ok(self.bus_voltage().await?.is_conversion_ready())
+    }
+
+    #[cfg(feature = "minimal")]
+    #[allow(
+        clippy::unused_self,
+        clippy::unnecessary_wraps,
+        clippy::missing_const_for_fn
+    )]
+    fn bus_voltage_from_register(
+        &mut self,
+        value: BusVoltageRegister,
+    ) -> Result<BusVoltage, BusVoltageReadError<I2C::Error>> {
+        // Minimal builds trust the device and skip the range check
+        Ok(BusVoltage::from_bits_unchecked(value))
+    }
+
+    #[cfg(not(feature = "minimal"))]
     #[cfg_attr(not(feature = "paranoid"), allow(clippy::unused_self))]
     fn bus_voltage_from_register(
         &mut self,
@@ -382,6 +1269,45 @@ where
         })
     }
 
+    /// Read the last measured bus and shunt voltage in a single transaction
+    ///
+    /// This needs half the bus traffic of calling [`Self::bus_voltage`] and
+    /// [`Self::shunt_voltage`] one after another. With feature `no_transaction` two separate reads
+    /// are performed instead.
+    ///
+    /// # Errors
+    /// Returns an error if the underlying I2C device returns an error or when any of the voltages
+    /// is outside of the expected range given in the last written configuration.
+    pub async fn voltages(
+        &mut self,
+    ) -> Result<(BusVoltage, ShuntVoltage), MeasurementError<I2C::Error>> {
+        let (bus_voltage, shunt_voltage) = self.read2().await?;
+
+        let bus_voltage = self.bus_voltage_from_register(bus_voltage)?;
+        let shunt_voltage = self.shunt_voltage_from_register(shunt_voltage)?;
+
+        Ok((bus_voltage, shunt_voltage))
+    }
+
+    /// Read the last measured bus and shunt voltage in a single transaction
+    ///
+    /// Next to both voltages the supply voltage in mV is returned. This is the voltage on the
+    /// supply side of the shunt, i.e. the sum of the bus and shunt voltage.
+    ///
+    /// # Errors
+    /// Returns an error if the underlying I2C device returns an error or when any of the voltages
+    /// is outside of the expected range given in the last written configuration.
+    pub async fn read_voltages_full(
+        &mut self,
+    ) -> Result<(BusVoltage, ShuntVoltage, i32), MeasurementError<I2C::Error>> {
+        let (bus_voltage, shunt_voltage) = self.voltages().await?;
+
+        let supply_uv =
+            i32::from(bus_voltage.voltage_mv()) * 1000 + shunt_voltage.shunt_voltage_uv();
+
+        Ok((bus_voltage, shunt_voltage, supply_uv / 1000))
+    }
+
     /// Read the last measured power
     ///
     /// # Errors
@@ -398,14 +1324,113 @@ where
         self.read().await
     }
 
+    /// Read the raw contents of a single register
+    ///
+    /// This is an escape hatch for registers or bits the typed API does not cover.
+    ///
+    /// # Errors
+    /// Returns an error if the underlying I2C device returns an error.
+    pub async fn read_register(&mut self, reg: RegisterName) -> Result<u16, I2C::Error> {
+        self.read_bits(reg as u8).await
+    }
+
+    /// Write a raw value to a single register
+    ///
+    /// This is an escape hatch for registers or bits the typed API does not cover. The driver does
+    /// not track what is written here, so writing the calibration register leaves the driver's
+    /// calibration out of sync with the device.
+    ///
+    /// *With feature `paranoid`*: Writing the configuration register discards the saved
+    /// configuration.
+    ///
+    /// # Errors
+    /// Returns an error if the underlying I2C device returns an error.
+    pub async fn write_register(
+        &mut self,
+        reg: RegisterName,
+        value: u16,
+    ) -> Result<(), I2C::Error> {
+        #[cfg(feature = "paranoid")]
+        if reg == RegisterName::Configuration {
+            self.config = None;
+        }
+
+        let [val0, val1] = value.to_be_bytes();
+        self.i2c
+            .write(self.address.as_byte(), &[reg as u8, val0, val1])
+            .await
+    }
+
+    /// Read the raw contents of several registers into `buf`
+    ///
+    /// Each register fills two consecutive bytes of `buf` in the order of `regs`. The bytes are
+    /// big-endian as sent by the device. All registers are read in a single I2C transaction unless
+    /// feature `no_transaction` is enabled.
+    ///
+    /// # Errors
+    /// Returns an error if the underlying I2C device returns an error.
+    ///
+    /// # Panics
+    /// Panics if more than 6 registers are given or `buf` is not exactly twice as long as `regs`.
+    pub async fn read_into(
+        &mut self,
+        regs: &[RegisterName],
+        buf: &mut [u8],
+    ) -> Result<(), I2C::Error> {
+        const MAX_REGS: usize = 6;
+
+        assert!(
+            regs.len() <= MAX_REGS,
+            "At most {MAX_REGS} registers can be read at once"
+        );
+        assert_eq!(
+            buf.len(),
+            regs.len() * 2,
+            "Buffer needs two bytes per register"
+        );
+
+        let addr = self.address.as_byte();
+        if cfg!(feature = "no_transaction") {
+            for (reg, chunk) in regs.iter().zip(buf.chunks_exact_mut(2)) {
+                self.i2c.write_read(addr, &[*reg as u8], chunk).await?;
+            }
+            return Ok(());
+        }
+
+        let mut reg_addrs = [[0u8; 1]; MAX_REGS];
+        for (reg_addr, reg) in reg_addrs.iter_mut().zip(regs) {
+            *reg_addr = [*reg as u8];
+        }
+
+        // Operations past the given registers are never used, they only fill the array
+        let mut chunks = buf.chunks_exact_mut(2);
+        let mut operations: [Operation; MAX_REGS * 2] = core::array::from_fn(|i| {
+            if i % 2 == 0 {
+                Operation::Write(&reg_addrs[i / 2])
+            } else {
+                Operation::Read(chunks.next().unwrap_or(&mut []))
+            }
+        });
+
+        self.i2c
+            .transaction(addr, &mut operations[..regs.len() * 2])
+            .await
+    }
+
     async fn read<Reg: register::ReadRegister>(&mut self) -> Result<Reg, I2C::Error> {
+        Ok(Reg::from_bits(self.read_bits(Reg::ADDRESS).await?))
+    }
+
+    /// Read the raw bits of the register at `address`
+    async fn read_bits(&mut self, address: u8) -> Result<u16, I2C::Error> {
         let mut buf: [u8; 2] = [0x00; 2];
         self.i2c
-            .write_read(self.address.as_byte(), &[Reg::ADDRESS], &mut buf)
+            .write_read(self.address.as_byte(), &[address], &mut buf)
             .await?;
-        Ok(Reg::from_bits(u16::from_be_bytes(buf)))
+        Ok(u16::from_be_bytes(buf))
     }
 
+    read_many!(read2, (R0, b0), (R1, b1));
     read_many!(read3, (R0, b0), (R1, b1), (R2, b2));
     read_many!(read4, (R0, b0), (R1, b1), (R2, b2), (R3, b3));
 
@@ -426,6 +1451,8 @@ async fn write<I2C: I2c, Reg: WriteRegister>(
     dev.write(addr.as_byte(), &[Reg::ADDRESS, val0, val1]).await
 }
 
+// The INA219 does not auto-increment its register pointer, a longer read just repeats the same
+// register. So every register needs its own pointer write, a single burst read is not possible.
 macro_rules! read_many {
     ($name:ident, $(($reg:ident, $buf:ident)),+) => {
         async fn $name<$($reg),+>(&mut self) -> Result<($($reg,)+), I2C::Error>
@@ -450,3 +1477,10 @@ macro_rules! read_many {
     };
 }
 use read_many;
+
+/// Delay that returns immediately, used when no delay is given
+struct NoDelay;
+
+impl DelayNs for NoDelay {
+    async fn delay_ns(&mut self, _ns: u32) {}
+}
@@ -0,0 +1,114 @@
+//! Helpers that only make sense for the blocking driver
+
+use crate::address::Address;
+use crate::calibration::Calibration;
+use crate::errors::MeasurementError;
+use crate::measurements::Measurements;
+use crate::register::Register;
+use crate::sync::INA219;
+use embedded_hal::delay::DelayNs;
+use embedded_hal::i2c::I2c;
+
+impl<I2C, Calib> INA219<I2C, Calib>
+where
+    I2C: I2c,
+    Calib: Calibration,
+{
+    /// Block until the next measurement is ready using `delay` and return it
+    ///
+    /// This is the same as [`Self::measure`], the name only makes it explicit that the current
+    /// thread is blocked for at least the conversion time.
+    ///
+    /// # Errors
+    /// Returns an error if the underlying I2C device returns an error, when any of the
+    /// measurements is outside of their expected ranges, when the device is not configured to
+    /// perform conversions or when the conversion takes too long.
+    #[allow(clippy::type_complexity)] // FIXME: Find a more elegant type
+    pub fn measure_blocking(
+        &mut self,
+        delay: &mut impl DelayNs,
+    ) -> Result<Measurements<Calib::Current, Calib::Power>, MeasurementError<I2C::Error>> {
+        self.measure(delay)
+    }
+
+    /// Continuously measure using `delay` to wait for each conversion
+    ///
+    /// Every item is the result of [`Self::measure`], so the iterator waits for the conversion
+    /// time of the configuration and yields each completed conversion. The iterator never ends on
+    /// its own, except after [`MeasurementError::DeviceNotConverting`] since no measurement will
+    /// arrive after that.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # fn sample(
+    /// #     mut ina: ina219::SyncIna219<impl embedded_hal::i2c::I2c, ina219::calibration::UnCalibrated>,
+    /// #     mut delay: impl embedded_hal::delay::DelayNs,
+    /// # ) {
+    /// for m in ina.measurements(&mut delay).take(100) {
+    ///     println!("{:?}", m.map(|m| m.bus_voltage));
+    /// }
+    /// # }
+    /// ```
+    pub const fn measurements<'a, D: DelayNs>(
+        &'a mut self,
+        delay: &'a mut D,
+    ) -> MeasurementIter<'a, I2C, Calib, D> {
+        MeasurementIter {
+            ina: self,
+            delay,
+            done: false,
+        }
+    }
+}
+
+/// Iterator over continuous measurements, see [`INA219::measurements`]
+pub struct MeasurementIter<'a, I2C, Calib, D> {
+    ina: &'a mut INA219<I2C, Calib>,
+    delay: &'a mut D,
+    done: bool,
+}
+
+impl<I2C, Calib, D> Iterator for MeasurementIter<'_, I2C, Calib, D>
+where
+    I2C: I2c,
+    Calib: Calibration,
+    D: DelayNs,
+{
+    type Item = Result<Measurements<Calib::Current, Calib::Power>, MeasurementError<I2C::Error>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let result = self.ina.measure(self.delay);
+        self.done = matches!(result, Err(MeasurementError::DeviceNotConverting));
+        Some(result)
+    }
+}
+
+/// Find the addresses at which an INA219 responds on the bus
+///
+/// Each of the 16 possible [`Address`]es is probed lazily by reading its configuration register.
+/// Addresses where this read fails, e.g. because nothing acknowledged, are skipped. Other devices
+/// on the bus using an address in the same range will be reported as well.
+///
+/// # Example
+/// ```no_run
+/// # fn scan(mut i2c: impl embedded_hal::i2c::I2c) {
+/// for address in ina219::scan(&mut i2c) {
+///     println!("Found INA219 at {:#04x}", address.as_byte());
+/// }
+/// # }
+/// ```
+pub fn scan<I2C: I2c>(i2c: &mut I2C) -> impl Iterator<Item = Address> + '_ {
+    Address::all().filter(move |address| {
+        let mut buf = [0; 2];
+        i2c.write_read(
+            address.as_byte(),
+            &[crate::configuration::Configuration::ADDRESS],
+            &mut buf,
+        )
+        .is_ok()
+    })
+}
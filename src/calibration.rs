@@ -4,11 +4,14 @@
 //! And it only saves two multiplications in software. So usage of this module should be well reasoned and errors be
 //! accounted for.
 
+use crate::configuration::{OperatingMode, ShuntVoltageRange};
 use crate::errors::MeasurementError;
-use crate::measurements::{BusVoltage, CurrentRegister, Measurements, PowerRegister, ShuntVoltage};
+use crate::measurements::{
+    sign_extend, BusVoltage, CurrentRegister, Measurements, PowerRegister, ShuntVoltage,
+};
 use crate::register::{ReadRegister, Register, WriteRegister};
 use core::fmt::{Display, Formatter};
-use core::ops::RangeInclusive;
+use core::ops::{Add, AddAssign, Div, Mul, Neg, RangeInclusive, Sub, SubAssign};
 
 /// Trait describing a calibration for the INA219
 pub trait Calibration {
@@ -33,6 +36,24 @@ pub trait Calibration {
     fn power_from_register(&self, reg: PowerRegister) -> Self::Power;
 }
 
+/// Suspicious device state found while writing a calibration
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum CalibrationWarning {
+    /// The device is not performing conversions, so current and power stay stale until it resumes
+    DeviceNotConverting(OperatingMode),
+}
+
+impl Display for CalibrationWarning {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::DeviceNotConverting(mode) => write!(
+                f,
+                "Device is in {mode}, current and power are stale until it resumes"
+            ),
+        }
+    }
+}
+
 /// Simulate the calculation a real INA219 would produce
 ///
 /// # Errors
@@ -59,12 +80,13 @@ pub fn simulate<C: Calibration>(
     bus_voltage: BusVoltage,
     shunt_voltage: ShuntVoltage,
 ) -> Result<Measurements<C::Current, C::Power>, MeasurementError<core::convert::Infallible>> {
-    const MAX: u32 = u16::MAX as u32;
+    const MAX: u64 = u16::MAX as u64;
 
-    let calib_reg: u32 = calib.register_bits().into();
-    let current = (u32::from(shunt_voltage.raw()) * calib_reg) / 4096;
+    // Calculated in u64 so the products can not overflow before they are checked against MAX
+    let calib_reg: u64 = calib.register_bits().into();
+    let current = (u64::from(shunt_voltage.raw()) * calib_reg) / 4096;
 
-    let power = (current * u32::from(bus_voltage.voltage_4mv())) / 5000;
+    let power = (current * u64::from(bus_voltage.voltage_4mv())) / 5000;
     if current > MAX || power > MAX {
         let on_error_measurement = Measurements {
             bus_voltage,
@@ -156,37 +178,147 @@ pub struct IntCalibration {
 }
 
 impl IntCalibration {
+    /// Ratio between the least significant bit of the power register and the current register
+    ///
+    /// The power LSB is fixed by the datasheet at 20 times the current LSB.
+    pub const POWER_TO_CURRENT_LSB_RATIO: i64 = 20;
+
     /// Create a new calibration using the least significant bit (LSB) of the current register in µV
     /// and the value of the shunt resistor used in µOhm
-
-    // TODO: Add nicer error
+    ///
+    /// # Errors
+    /// Returns an error if the current LSB is negative or the product of current LSB and shunt
+    /// resistance does not result in a value that fits into the calibration register.
     // TODO: Handle error introduced during calculation...
-    #[must_use]
-    pub fn new(current_lsb: MicroAmpere, r_shunt_uohm: u32) -> Option<Self> {
-        if current_lsb.0 < 0 {
-            return None;
-        }
-        let product = u64::try_from(current_lsb.0).ok()? * u64::from(r_shunt_uohm);
+    pub fn new(current_lsb: MicroAmpere, r_shunt_uohm: u32) -> Result<Self, CalibrationError> {
+        let Ok(current_lsb_unsigned) = u64::try_from(current_lsb.0) else {
+            return Err(CalibrationError::CurrentLsbNegative);
+        };
+        let product = current_lsb_unsigned
+            .checked_mul(u64::from(r_shunt_uohm))
+            .ok_or(CalibrationError::ProductTooLarge)?;
 
-        if RANGE.contains(&product) {
-            Some(Self {
+        if product < *RANGE.start() {
+            Err(CalibrationError::ProductTooSmall)
+        } else if product > *RANGE.end() {
+            Err(CalibrationError::ProductTooLarge)
+        } else {
+            Ok(Self {
                 current_lsb,
                 r_shunt_uohm,
             })
-        } else {
-            None
         }
     }
 
-    /// Reconstruct the calibration from the value read from the calibration register
+    /// Create a calibration that can measure currents up to `max_current` with a shunt of
+    /// `r_shunt_uohm` µOhm
+    ///
+    /// The current LSB starts at the smallest value that can still represent `max_current`, which
+    /// is about `max_current / 32768` as recommended by the datasheet. It is rounded up to the next
+    /// value of the form 1, 2 or 5 times a power of ten to keep the measurements easy to read. If
+    /// the LSB is below [`IntCalibration::min_current_lsb`] that one is used instead. Returns
+    /// `None` if `max_current` is not positive or too large for the shunt.
+    ///
+    /// # Example
+    /// ```rust
+    /// use ina219::calibration::{IntCalibration, MicroAmpere};
+    ///
+    /// // Up to 3.2A with a 0.1Ohm shunt gives a resolution of 100µA
+    /// let calib = IntCalibration::from_max_current(MicroAmpere(3_200_000), 100_000).unwrap();
+    /// assert_eq!(calib.current_lsb(), MicroAmpere(100));
+    /// ```
     #[must_use]
-    pub fn from_bits(bits: u16, r_shunt_uohm: u32) -> Option<Self> {
-        if bits == 0 || r_shunt_uohm == 0 {
+    pub fn from_max_current(max_current: MicroAmpere, r_shunt_uohm: u32) -> Option<Self> {
+        if max_current.0 <= 0 || r_shunt_uohm == 0 {
             return None;
         }
 
+        let exact = max_current
+            .0
+            .unsigned_abs()
+            .div_ceil(i16::MAX.unsigned_abs().into());
+        let mut decade: u64 = 1;
+        let rounded = loop {
+            if let Some(nice) = [1, 2, 5]
+                .into_iter()
+                .map(|m| m * decade)
+                .find(|nice| *nice >= exact)
+            {
+                break nice;
+            }
+            decade = decade.checked_mul(10)?;
+        };
+
+        let min = Self::min_current_lsb(r_shunt_uohm);
+        let lsb = i64::try_from(rounded).ok()?.max(min.0);
+        Self::new(MicroAmpere(lsb), r_shunt_uohm).ok()
+    }
+
+    /// The smallest current LSB that can be used with a shunt of `r_shunt_uohm` µOhm
+    ///
+    /// This is the finest current resolution that can be achieved with the shunt, any smaller LSB
+    /// would not fit in the calibration register.
+    ///
+    /// # Panics
+    /// Panics if `r_shunt_uohm` is 0, no LSB fits a shunt without resistance.
+    ///
+    /// # Example
+    /// ```rust
+    /// use ina219::calibration::{IntCalibration, MicroAmpere};
+    ///
+    /// // With a 0.1Ohm shunt the current can be resolved in steps of 7µA
+    /// let lsb = IntCalibration::min_current_lsb(100_000);
+    /// assert_eq!(lsb, MicroAmpere(7));
+    /// assert!(IntCalibration::new(lsb, 100_000).is_ok());
+    /// assert!(IntCalibration::new(MicroAmpere(lsb.0 - 1), 100_000).is_err());
+    /// ```
+    #[must_use]
+    pub const fn min_current_lsb(r_shunt_uohm: u32) -> MicroAmpere {
+        // Both values are positive and the result is at most RANGE.start()
+        #[allow(clippy::cast_possible_wrap)]
+        MicroAmpere(RANGE.start().div_ceil(r_shunt_uohm as u64) as i64)
+    }
+
+    /// The largest current LSB that can be used with a shunt of `r_shunt_uohm` µOhm
+    ///
+    /// Together with [`IntCalibration::min_current_lsb`] this brackets all LSBs that are accepted
+    /// by [`IntCalibration::new`].
+    ///
+    /// # Panics
+    /// Panics if `r_shunt_uohm` is 0, no LSB fits a shunt without resistance.
+    ///
+    /// # Example
+    /// ```rust
+    /// use ina219::calibration::{IntCalibration, MicroAmpere};
+    ///
+    /// let lsb = IntCalibration::max_current_lsb(100_000);
+    /// assert_eq!(lsb, MicroAmpere(204_800));
+    /// assert!(IntCalibration::new(lsb, 100_000).is_ok());
+    /// assert!(IntCalibration::new(MicroAmpere(lsb.0 + 1), 100_000).is_err());
+    /// ```
+    #[must_use]
+    pub const fn max_current_lsb(r_shunt_uohm: u32) -> MicroAmpere {
+        // Both values are positive and the result is at most RANGE.end()
+        #[allow(clippy::cast_possible_wrap)]
+        MicroAmpere((*RANGE.end() / r_shunt_uohm as u64) as i64)
+    }
+
+    /// Reconstruct the calibration from the value read from the calibration register
+    ///
+    /// # Errors
+    /// Returns an error if the register value and shunt resistance do not describe a valid
+    /// calibration.
+    pub fn from_bits(bits: u16, r_shunt_uohm: u32) -> Result<Self, CalibrationError> {
+        if r_shunt_uohm == 0 {
+            return Err(CalibrationError::ProductTooSmall);
+        }
+        if bits == 0 {
+            return Err(CalibrationError::ProductTooLarge);
+        }
+
         let current_lsb =
-            i64::try_from(SCALING_FACTOR / (u64::from(bits) * u64::from(r_shunt_uohm))).ok()?;
+            i64::try_from(SCALING_FACTOR / (u64::from(bits) * u64::from(r_shunt_uohm)))
+                .map_err(|_| CalibrationError::ProductTooLarge)?;
 
         Self::new(MicroAmpere(current_lsb), r_shunt_uohm)
     }
@@ -220,10 +352,91 @@ impl IntCalibration {
         self.current_lsb
     }
 
+    /// The value of the least significant bit in the current register implied by the register
+    /// value, rounded to the nearest µA
+    ///
+    /// The calibration register only holds an even integer, so the device effectively uses a
+    /// slightly larger LSB than [`Self::current_lsb`]. See [`Self::quantization_error_ppm`] for
+    /// the size of the difference.
+    ///
+    /// # Example
+    /// ```rust
+    /// use ina219::calibration::{IntCalibration, MicroAmpere};
+    ///
+    /// let calib = IntCalibration::new(MicroAmpere(10), 1_000_000).unwrap();
+    /// assert_eq!(calib.as_bits(), 4_096);
+    /// assert_eq!(calib.effective_current_lsb(), MicroAmpere(10));
+    /// ```
+    #[must_use]
+    pub const fn effective_current_lsb(self) -> MicroAmpere {
+        let divisor = self.as_bits() as u64 * self.r_shunt_uohm as u64;
+        // At most SCALING_FACTOR / 2, so it always fits
+        #[allow(clippy::cast_possible_wrap)]
+        MicroAmpere(((SCALING_FACTOR + divisor / 2) / divisor) as i64)
+    }
+
+    /// Relative difference between [`Self::effective_current_lsb`] and [`Self::current_lsb`] in
+    /// parts per million, rounded to the nearest ppm
+    ///
+    /// All current and power measurements are off by this amount due to the rounding of the
+    /// calibration register.
+    ///
+    /// # Example
+    /// ```rust
+    /// use ina219::calibration::{IntCalibration, MicroAmpere};
+    ///
+    /// // The register value of 409.6 gets rounded down to 408
+    /// let calib = IntCalibration::new(MicroAmpere(100), 1_000_000).unwrap();
+    /// assert_eq!(calib.quantization_error_ppm(), 3_922);
+    /// ```
+    #[must_use]
+    pub const fn quantization_error_ppm(self) -> u32 {
+        // Both are positive, which is enforced by new/from_bits
+        #[allow(clippy::cast_sign_loss)]
+        let requested =
+            self.current_lsb.0 as u128 * self.as_bits() as u128 * self.r_shunt_uohm as u128;
+        let exact = SCALING_FACTOR as u128;
+        let diff = exact.abs_diff(requested);
+
+        let ppm = (diff * 1_000_000 + requested / 2) / requested;
+        if ppm > u32::MAX as u128 {
+            u32::MAX
+        } else {
+            // Checked above
+            #[allow(clippy::cast_possible_truncation)]
+            {
+                ppm as u32
+            }
+        }
+    }
+
     /// The value of the least significant bit in the power register in µW
+    ///
+    /// # Example
+    /// ```rust
+    /// use ina219::calibration::{IntCalibration, MicroAmpere, MicroWatt};
+    ///
+    /// let calib = IntCalibration::new(MicroAmpere(100), 1_000_000).unwrap();
+    /// let ratio = IntCalibration::POWER_TO_CURRENT_LSB_RATIO;
+    /// assert_eq!(calib.power_lsb(), MicroWatt(100 * ratio));
+    /// ```
     #[must_use]
     pub const fn power_lsb(self) -> MicroWatt {
-        MicroWatt(20 * self.current_lsb.0)
+        MicroWatt(Self::POWER_TO_CURRENT_LSB_RATIO * self.current_lsb.0)
+    }
+
+    /// The largest current the current register can represent with this calibration
+    #[must_use]
+    pub const fn max_current(self) -> MicroAmpere {
+        MicroAmpere(self.current_lsb.0 * i16::MAX as i64)
+    }
+
+    /// The largest current that can be measured with the shunt in the given shunt voltage range
+    #[must_use]
+    pub const fn range_max_current(self, range: ShuntVoltageRange) -> MicroAmpere {
+        // mV * 1_000_000_000 / µOhm = µA
+        let range_mv = *range.range_mv().end() as i64;
+        MicroAmpere(range_mv * 1_000_000_000 / self.r_shunt_uohm as i64)
     }
 
     /// The value of the shunt used in µOhm
@@ -231,10 +444,28 @@ impl IntCalibration {
     pub const fn r_shunt_uohm(self) -> u32 {
         self.r_shunt_uohm
     }
+
+    /// The value of the least significant bit in the current register in A
+    #[cfg(feature = "float")]
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)] // f32 is only used for display purposes
+    pub fn current_lsb_amps(self) -> f32 {
+        self.current_lsb.0 as f32 / 1_000_000.0
+    }
+
+    /// The value of the shunt used in Ohm
+    #[cfg(feature = "float")]
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)] // f32 is only used for display purposes
+    pub fn r_shunt_ohms(self) -> f32 {
+        self.r_shunt_uohm as f32 / 1_000_000.0
+    }
 }
 
 /// A current measurement in µA
-#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct MicroAmpere(pub i64);
 
 impl Display for MicroAmpere {
@@ -244,7 +475,9 @@ impl Display for MicroAmpere {
 }
 
 /// A power measurement in µW
-#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct MicroWatt(pub i64);
 
 impl Display for MicroWatt {
@@ -253,6 +486,194 @@ impl Display for MicroWatt {
     }
 }
 
+#[cfg(feature = "fixed")]
+impl MicroAmpere {
+    /// Return the current in A as a fixed-point number
+    ///
+    /// A µA is not exactly representable in binary, so the fractional part is truncated to the
+    /// resolution of `I32F32` (about 0.0002µA). Currents beyond the range of `I32F32` saturate.
+    #[must_use]
+    pub fn amperes_fixed(self) -> fixed::types::I32F32 {
+        micro_to_fixed(self.0)
+    }
+}
+
+#[cfg(feature = "fixed")]
+impl MicroWatt {
+    /// Return the power in W as a fixed-point number
+    ///
+    /// A µW is not exactly representable in binary, so the fractional part is truncated to the
+    /// resolution of `I32F32` (about 0.0002µW). Powers beyond the range of `I32F32` saturate.
+    #[must_use]
+    pub fn watts_fixed(self) -> fixed::types::I32F32 {
+        micro_to_fixed(self.0)
+    }
+}
+
+/// Turn a value with µ prefix into a fixed-point number in the base unit
+#[cfg(feature = "fixed")]
+fn micro_to_fixed(micro: i64) -> fixed::types::I32F32 {
+    use fixed::types::I32F32;
+
+    // Split before converting, so large values do not overflow while still being scaled by 10^6
+    let whole = I32F32::saturating_from_num(micro / 1_000_000);
+    let fraction = I32F32::from_num(micro % 1_000_000) / 1_000_000;
+    whole.saturating_add(fraction)
+}
+
+/// Implement arithmetic between values of the same unit and scaling by plain numbers
+///
+/// Scaling is done by the inner integer type `$scalar`, overflow behaves like it does for it.
+macro_rules! impl_unit_arithmetic {
+    ($unit:ident, $scalar:ty) => {
+        impl Add for $unit {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                Self(self.0 + rhs.0)
+            }
+        }
+
+        impl Sub for $unit {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                Self(self.0 - rhs.0)
+            }
+        }
+
+        impl AddAssign for $unit {
+            fn add_assign(&mut self, rhs: Self) {
+                self.0 += rhs.0;
+            }
+        }
+
+        impl SubAssign for $unit {
+            fn sub_assign(&mut self, rhs: Self) {
+                self.0 -= rhs.0;
+            }
+        }
+
+        impl Neg for $unit {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self(-self.0)
+            }
+        }
+
+        impl Mul<$scalar> for $unit {
+            type Output = Self;
+
+            fn mul(self, rhs: $scalar) -> Self {
+                Self(self.0 * rhs)
+            }
+        }
+
+        impl Div<$scalar> for $unit {
+            type Output = Self;
+
+            fn div(self, rhs: $scalar) -> Self {
+                Self(self.0 / rhs)
+            }
+        }
+    };
+}
+
+pub(crate) use impl_unit_arithmetic;
+
+impl_unit_arithmetic!(MicroAmpere, i64);
+impl_unit_arithmetic!(MicroWatt, i64);
+
+/// Energy in µJ between two power samples taken `dt_us` µs apart
+///
+/// Integrates the power using the trapezoidal rule. The result saturates at the bounds of `i64`.
+///
+/// # Example
+/// ```
+/// use ina219::calibration::{energy_uj, MicroWatt};
+///
+/// // 1W to 3W over one second averages to 2W, which is 2J
+/// assert_eq!(energy_uj(MicroWatt(1_000_000), MicroWatt(3_000_000), 1_000_000), 2_000_000);
+/// ```
+#[must_use]
+pub fn energy_uj(p0: MicroWatt, p1: MicroWatt, dt_us: u64) -> i64 {
+    trapezoid(p0.0, p1.0, dt_us)
+}
+
+/// Charge in µC between two current samples taken `dt_us` µs apart
+///
+/// Integrates the current using the trapezoidal rule. The result saturates at the bounds of `i64`.
+///
+/// # Example
+/// ```
+/// use ina219::calibration::{charge_uc, MicroAmpere};
+///
+/// // A constant 500mA for 2 seconds is 1C
+/// assert_eq!(charge_uc(MicroAmpere(500_000), MicroAmpere(500_000), 2_000_000), 1_000_000);
+/// ```
+#[must_use]
+pub fn charge_uc(i0: MicroAmpere, i1: MicroAmpere, dt_us: u64) -> i64 {
+    trapezoid(i0.0, i1.0, dt_us)
+}
+
+/// Resistance of the shunt in µOhm derived from a shunt voltage measured at a known current
+///
+/// Use this with a reference load to find the actual value of the shunt, which can deviate from
+/// its nominal value by the tolerance of the resistor. The result is rounded to the nearest µOhm.
+///
+/// Returns `None` if the current is zero, the voltage and current have opposite signs or the
+/// result does not fit into a `u32`.
+///
+/// # Example
+/// ```
+/// use ina219::calibration::{compute_shunt_uohm, MicroAmpere};
+/// use ina219::measurements::ShuntVoltage;
+///
+/// // 10.2mV at 100mA means the nominal 0.1Ohm shunt is actually 0.102Ohm
+/// let shunt = ShuntVoltage::from_10uv(1_020);
+/// assert_eq!(compute_shunt_uohm(shunt, MicroAmpere(100_000)), Some(102_000));
+/// ```
+#[must_use]
+pub fn compute_shunt_uohm(shunt: ShuntVoltage, known_current: MicroAmpere) -> Option<u32> {
+    if known_current.0 == 0 {
+        return None;
+    }
+
+    let uv = i64::from(shunt.shunt_voltage_uv());
+    if uv.signum() * known_current.0.signum() < 0 {
+        return None;
+    }
+
+    // µV * 1_000_000 / µA = µOhm
+    let uv = u128::from(uv.unsigned_abs()) * 1_000_000;
+    let ua = u128::from(known_current.0.unsigned_abs());
+    u32::try_from((uv + ua / 2) / ua).ok()
+}
+
+/// Integrate between two samples of a µ-unit over `dt_us` µs, the result is again in the µ-unit
+fn trapezoid(y0: i64, y1: i64, dt_us: u64) -> i64 {
+    // µ-unit * µs = µ-unit * s / 1_000_000, and the average needs another factor of two
+    let area = (i128::from(y0) + i128::from(y1)).saturating_mul(i128::from(dt_us)) / 2_000_000;
+    i64::try_from(area).unwrap_or(if area < 0 { i64::MIN } else { i64::MAX })
+}
+
+#[cfg(feature = "uom")]
+#[allow(clippy::cast_precision_loss)] // Only values beyond 2^53 µA lose precision
+impl From<MicroAmpere> for uom::si::f64::ElectricCurrent {
+    fn from(value: MicroAmpere) -> Self {
+        Self::new::<uom::si::electric_current::microampere>(value.0 as f64)
+    }
+}
+
+#[cfg(feature = "uom")]
+#[allow(clippy::cast_precision_loss)] // Only values beyond 2^53 µW lose precision
+impl From<MicroWatt> for uom::si::f64::Power {
+    fn from(value: MicroWatt) -> Self {
+        Self::new::<uom::si::power::microwatt>(value.0 as f64)
+    }
+}
+
 impl Calibration for IntCalibration {
     type Current = MicroAmpere;
     type Power = MicroWatt;
@@ -262,20 +683,155 @@ impl Calibration for IntCalibration {
     }
 
     fn current_from_register(&self, reg: CurrentRegister) -> Self::Current {
-        MicroAmpere(self.current_lsb().0 * i64_from_signed_register(reg.0))
+        MicroAmpere(self.current_lsb().0 * i64::from(sign_extend(reg.0)))
     }
 
     fn power_from_register(&self, reg: PowerRegister) -> Self::Power {
-        MicroWatt(self.power_lsb().0 * i64_from_signed_register(reg.0))
+        MicroWatt(self.power_lsb().0 * i64::from(sign_extend(reg.0)))
     }
 }
 
-fn i64_from_signed_register(bits: u16) -> i64 {
-    let sixteen = i16::from_ne_bytes(bits.to_ne_bytes());
-    i64::from(sixteen)
+/// Reason why the values of an [`IntCalibration`] are invalid
+///
+/// The product of current LSB and shunt resistance determines the value of the calibration
+/// register, which has to fit into its 16 bits.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum CalibrationError {
+    /// The current LSB is negative
+    CurrentLsbNegative,
+    /// The product of current LSB and shunt resistance is too small, the calibration register
+    /// would overflow
+    ProductTooSmall,
+    /// The product of current LSB and shunt resistance is too large, the calibration register
+    /// would be zero
+    ProductTooLarge,
+}
+
+impl Display for CalibrationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::CurrentLsbNegative => write!(f, "Current LSB is negative"),
+            Self::ProductTooSmall => write!(
+                f,
+                "Product of current LSB and shunt is too small, should be at least {}µA*µOhm",
+                RANGE.start()
+            ),
+            Self::ProductTooLarge => write!(
+                f,
+                "Product of current LSB and shunt is too large, should be at most {}µA*µOhm",
+                RANGE.end()
+            ),
+        }
+    }
 }
 
-pub(crate) struct RawCalibration(pub u16);
+impl core::error::Error for CalibrationError {}
+
+/// Calibration using floating-point numbers in base SI units
+///
+/// This is convenient when the shunt and current LSB are already known as floats, e.g. from a
+/// datasheet. Current is returned in A and power in W.
+///
+/// **Note:** `f32` has a precision of roughly 7 significant digits. The value written to the
+/// calibration register can therefore be off by one compared to [`IntCalibration`] for some
+/// inputs, and large register values lose precision when converted to current or power.
+#[cfg(feature = "float")]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct FloatCalibration {
+    /// Value of the least significant bit of the current in A
+    current_lsb_a: f32,
+
+    /// Resistance of the shunt resistor in Ohm
+    r_shunt_ohm: f32,
+
+    /// Value of the calibration register
+    bits: u16,
+}
+
+#[cfg(feature = "float")]
+impl FloatCalibration {
+    /// Ratio between the least significant bit of the power register and the current register
+    pub const POWER_TO_CURRENT_LSB_RATIO: f32 = 20.0;
+
+    /// Create a new calibration using the least significant bit (LSB) of the current register in A
+    /// and the value of the shunt resistor used in Ohm
+    ///
+    /// Returns `None` if the resulting calibration does not fit into the calibration register.
+    ///
+    /// # Example
+    /// ```rust
+    /// use ina219::calibration::{Calibration, FloatCalibration};
+    ///
+    /// let calib = FloatCalibration::new(0.001, 0.1).unwrap(); // 1mA, 0.1Ohm
+    /// assert_eq!(calib.register_bits(), 408);
+    /// ```
+    #[must_use]
+    pub fn new(current_lsb_a: f32, r_shunt_ohm: f32) -> Option<Self> {
+        // Scaling factor from the datasheet
+        const SCALING_FACTOR: f32 = 0.04096;
+
+        if current_lsb_a <= 0.0 || r_shunt_ohm <= 0.0 {
+            return None;
+        }
+
+        let cal = SCALING_FACTOR / (current_lsb_a * r_shunt_ohm);
+        if !(2.0..=f32::from(u16::MAX)).contains(&cal) {
+            return None;
+        }
+
+        // The range was checked above and the cast truncates like the datasheet formula
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Some(Self {
+            current_lsb_a,
+            r_shunt_ohm,
+            // According to Figure 27 of the datasheet the lowest bit is always 0
+            bits: (cal as u16) & !1,
+        })
+    }
+
+    /// The value of the least significant bit in the current register in A
+    #[must_use]
+    pub const fn current_lsb_a(self) -> f32 {
+        self.current_lsb_a
+    }
+
+    /// The value of the least significant bit in the power register in W
+    #[must_use]
+    pub fn power_lsb_w(self) -> f32 {
+        Self::POWER_TO_CURRENT_LSB_RATIO * self.current_lsb_a
+    }
+
+    /// The value of the shunt used in Ohm
+    #[must_use]
+    pub const fn r_shunt_ohm(self) -> f32 {
+        self.r_shunt_ohm
+    }
+}
+
+#[cfg(feature = "float")]
+impl Calibration for FloatCalibration {
+    type Current = f32;
+    type Power = f32;
+
+    fn register_bits(&self) -> u16 {
+        self.bits
+    }
+
+    fn current_from_register(&self, reg: CurrentRegister) -> Self::Current {
+        f32::from(sign_extend(reg.0)) * self.current_lsb_a
+    }
+
+    fn power_from_register(&self, reg: PowerRegister) -> Self::Power {
+        f32::from(reg.0) * self.power_lsb_w()
+    }
+}
+
+/// Calibration given directly as the value of the calibration register
+///
+/// Use this to replicate a known-good calibration register value without going through the
+/// calculations of [`IntCalibration`]. Current and power are returned as the raw register values.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub struct RawCalibration(pub u16);
 
 impl Calibration for RawCalibration {
     type Current = u16;
@@ -304,6 +860,43 @@ impl ReadRegister for RawCalibration {
 mod tests {
     use super::*;
 
+    #[test]
+    #[cfg(feature = "fixed")]
+    fn fixed_point() {
+        use fixed::types::I32F32;
+
+        assert_eq!(
+            MicroAmpere(2_500_000).amperes_fixed(),
+            I32F32::from_num(2.5)
+        );
+        assert_eq!(
+            MicroAmpere(-1_500_000).amperes_fixed(),
+            I32F32::from_num(-1.5)
+        );
+        assert_eq!(MicroWatt(250_000).watts_fixed(), I32F32::from_num(0.25));
+        assert!(MicroAmpere(1).amperes_fixed() > I32F32::ZERO);
+        assert_eq!(MicroWatt(i64::MAX).watts_fixed(), I32F32::MAX);
+    }
+
+    #[test]
+    fn simulate_large_product_overflows() {
+        // current * bus voltage does not fit into u32
+        let result = simulate(
+            &RawCalibration(u16::MAX),
+            BusVoltage::from_mv(31_996),
+            ShuntVoltage::from_10uv(-1),
+        );
+        assert!(matches!(result, Err(MeasurementError::MathOverflow(_))));
+
+        // A current near the maximum at 32V overflows the power register
+        let result = simulate(
+            &RawCalibration(8_190),
+            BusVoltage::from_mv(31_996),
+            ShuntVoltage::from_10uv(32_000),
+        );
+        assert!(matches!(result, Err(MeasurementError::MathOverflow(_))));
+    }
+
     #[allow(clippy::cast_precision_loss)] // This is only used in tests
     fn as_bits_datasheet(cal: IntCalibration) -> u16 {
         let micro = 1.0 / 1_000_000.0;
@@ -326,10 +919,225 @@ mod tests {
     fn calculation_fits_datasheet() {
         for i in 1..=1_000 {
             for r in 1..=1_000 {
-                if let Some(cal) = IntCalibration::new(MicroAmpere(i), r) {
+                if let Ok(cal) = IntCalibration::new(MicroAmpere(i), r) {
                     assert_eq!(as_bits_datasheet(cal), cal.as_bits());
                 }
             }
         }
     }
+
+    #[test]
+    #[cfg(feature = "float")]
+    #[allow(clippy::cast_precision_loss)] // Values are small enough to be exact
+    fn float_calibration() {
+        assert_eq!(FloatCalibration::new(0.0, 0.1), None);
+        assert_eq!(FloatCalibration::new(0.001, -0.1), None);
+        assert_eq!(FloatCalibration::new(1.0, 1.0), None);
+        assert_eq!(FloatCalibration::new(f32::NAN, 1.0), None);
+
+        // Same register value as the integer calibration, allowing for the f32 rounding
+        for i in (10..=1_000).step_by(7) {
+            for r in (1_000..=1_000_000).step_by(9_973) {
+                if let Ok(cal) = IntCalibration::new(MicroAmpere(i), r) {
+                    let float = FloatCalibration::new(i as f32 * 1e-6, r as f32 * 1e-6)
+                        .expect("Fits like the integer calibration");
+                    assert!(cal.as_bits().abs_diff(float.register_bits()) <= 2);
+                }
+            }
+        }
+
+        let calib = FloatCalibration::new(0.001, 0.1).unwrap();
+        let current = calib.current_from_register(CurrentRegister(0xFFFF));
+        assert!((current + 0.001).abs() < f32::EPSILON);
+        let power = calib.power_from_register(PowerRegister(500));
+        assert!((power - 10.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn calibration_errors() {
+        use CalibrationError::*;
+
+        assert_eq!(
+            IntCalibration::new(MicroAmpere(-1), 1_000),
+            Err(CurrentLsbNegative)
+        );
+        assert_eq!(IntCalibration::new(MicroAmpere(1), 0), Err(ProductTooSmall));
+        assert_eq!(
+            IntCalibration::new(MicroAmpere(i64::MAX), u32::MAX),
+            Err(ProductTooLarge)
+        );
+        assert_eq!(IntCalibration::from_bits(0, 1_000), Err(ProductTooLarge));
+        assert_eq!(IntCalibration::from_bits(2, 0), Err(ProductTooSmall));
+    }
+
+    #[test]
+    fn quantization_error() {
+        // Exactly representable
+        let calib = IntCalibration::new(MicroAmpere(20), 1_000_000).unwrap();
+        assert_eq!(calib.as_bits(), 2_048);
+        assert_eq!(calib.effective_current_lsb(), MicroAmpere(20));
+        assert_eq!(calib.quantization_error_ppm(), 0);
+
+        // 40.96 is truncated to 40
+        let calib = IntCalibration::new(MicroAmpere(1_000), 1_000_000).unwrap();
+        assert_eq!(calib.as_bits(), 40);
+        assert_eq!(calib.effective_current_lsb(), MicroAmpere(1_024));
+        assert_eq!(calib.quantization_error_ppm(), 24_000);
+
+        // 3 is odd so the register value is 2
+        let calib = IntCalibration::new(MicroAmpere(13_653_333), 1_000).unwrap();
+        assert_eq!(calib.as_bits(), 2);
+        assert_eq!(calib.effective_current_lsb(), MicroAmpere(20_480_000));
+        assert_eq!(calib.quantization_error_ppm(), 500_000);
+    }
+
+    #[test]
+    fn from_max_current() {
+        let lsb = |max, r| {
+            IntCalibration::from_max_current(MicroAmpere(max), r).map(|c| c.current_lsb().0)
+        };
+
+        assert_eq!(lsb(0, 100_000), None);
+        assert_eq!(lsb(-1_000, 100_000), None);
+        assert_eq!(lsb(1_000, 0), None);
+
+        assert_eq!(lsb(32_768, 100_000), Some(7)); // Limited by the shunt
+        assert_eq!(lsb(1_000_000, 100_000), Some(50));
+        assert_eq!(lsb(2_000_000, 100_000), Some(100));
+        assert_eq!(lsb(3_276_700, 100_000), Some(100));
+        assert_eq!(lsb(3_276_701, 100_000), Some(200));
+        assert_eq!(lsb(16_000_000, 2_000), Some(500));
+        assert_eq!(lsb(16_000_000, 1_000), Some(626)); // Limited by the shunt
+        assert_eq!(lsb(i64::MAX, 100_000), None); // Too large for the shunt
+
+        for max in (1..=100_000_000)
+            .step_by(9_973)
+            .chain([3_276_700, 32_767_000])
+        {
+            if let Some(calib) = IntCalibration::from_max_current(MicroAmpere(max), 100_000) {
+                assert!(calib.max_current().0 >= max);
+            }
+        }
+    }
+
+    #[test]
+    fn shunt_from_known_current() {
+        let shunt = ShuntVoltage::from_10uv(1_000); // 10mV
+        assert_eq!(compute_shunt_uohm(shunt, MicroAmpere(0)), None);
+        assert_eq!(compute_shunt_uohm(shunt, MicroAmpere(-1_000)), None);
+        assert_eq!(compute_shunt_uohm(shunt, MicroAmpere(1)), None);
+        assert_eq!(
+            compute_shunt_uohm(shunt, MicroAmpere(10_000)),
+            Some(1_000_000)
+        );
+        assert_eq!(
+            compute_shunt_uohm(shunt, MicroAmpere(30_000)),
+            Some(333_333)
+        );
+        assert_eq!(
+            compute_shunt_uohm(shunt, MicroAmpere(60_000)),
+            Some(166_667)
+        );
+
+        let shunt = ShuntVoltage::from_10uv(-1_000);
+        assert_eq!(
+            compute_shunt_uohm(shunt, MicroAmpere(-60_000)),
+            Some(166_667)
+        );
+        assert_eq!(compute_shunt_uohm(shunt, MicroAmpere(60_000)), None);
+    }
+
+    #[test]
+    fn unit_arithmetic() {
+        let mut total = MicroAmpere(0);
+        for sample in [MicroAmpere(100), MicroAmpere(-30), MicroAmpere(50)] {
+            total += sample;
+        }
+        assert_eq!(total, MicroAmpere(120));
+        total -= MicroAmpere(20);
+        assert_eq!(total / 4, MicroAmpere(25));
+        assert_eq!(total * 3, MicroAmpere(300));
+        assert_eq!(-total, MicroAmpere(-100));
+        assert_eq!(total - MicroAmpere(150) + MicroAmpere(1), MicroAmpere(-49));
+        assert!(MicroAmpere(-1) < MicroAmpere(0));
+
+        let power = [MicroWatt(5), MicroWatt(-7), MicroWatt(2)];
+        assert_eq!(power.iter().copied().max(), Some(MicroWatt(5)));
+        assert_eq!(power.iter().fold(MicroWatt(0), |a, b| a + *b), MicroWatt(0));
+    }
+
+    #[test]
+    fn integration() {
+        assert_eq!(energy_uj(MicroWatt(0), MicroWatt(0), u64::MAX), 0);
+        assert_eq!(energy_uj(MicroWatt(-2), MicroWatt(-2), 1_000_000), -2);
+
+        // Ramp from 0 to 1W over one millisecond is 0.5mJ
+        assert_eq!(energy_uj(MicroWatt(0), MicroWatt(1_000_000), 1_000), 500);
+
+        assert_eq!(
+            charge_uc(MicroAmpere(i64::MAX), MicroAmpere(i64::MAX), u64::MAX),
+            i64::MAX
+        );
+        assert_eq!(
+            charge_uc(MicroAmpere(i64::MIN), MicroAmpere(i64::MIN), u64::MAX),
+            i64::MIN
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "uom")]
+    fn uom_conversion() {
+        use uom::si::electric_current::milliampere;
+        use uom::si::f64::{ElectricCurrent, Power};
+        use uom::si::power::watt;
+
+        let current = ElectricCurrent::from(MicroAmpere(-1_500));
+        assert!((current.get::<milliampere>() + 1.5).abs() < 1e-9);
+
+        let power = Power::from(MicroWatt(2_000_000));
+        assert!((power.get::<watt>() - 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn warning_display() {
+        let warning = CalibrationWarning::DeviceNotConverting(OperatingMode::PowerDown);
+        assert_eq!(
+            warning.to_string(),
+            "Device is in power down, current and power are stale until it resumes"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "float")]
+    fn si_units() {
+        let calib = IntCalibration::new(MicroAmpere(1_000), 100_000).unwrap();
+        assert!((calib.current_lsb_amps() - 0.001).abs() < f32::EPSILON);
+        assert!((calib.r_shunt_ohms() - 0.1).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn min_current_lsb() {
+        for r in (1..=u32::MAX).step_by(9_973) {
+            let lsb = IntCalibration::min_current_lsb(r);
+            assert!(IntCalibration::new(lsb, r).is_ok(), "{lsb:?} {r}");
+            assert_eq!(
+                IntCalibration::new(MicroAmpere(lsb.0 - 1), r),
+                Err(CalibrationError::ProductTooSmall)
+            );
+        }
+    }
+
+    #[test]
+    fn max_current_lsb() {
+        for r in (1..=u32::MAX).step_by(9_973) {
+            let min = IntCalibration::min_current_lsb(r);
+            let max = IntCalibration::max_current_lsb(r);
+            assert!(min <= max, "{min:?} {max:?} {r}");
+            assert!(IntCalibration::new(max, r).is_ok(), "{max:?} {r}");
+            assert_eq!(
+                IntCalibration::new(MicroAmpere(max.0 + 1), r),
+                Err(CalibrationError::ProductTooLarge)
+            );
+        }
+    }
 }
@@ -12,6 +12,15 @@
 //!     .. Default::default()
 //! };
 //! ```
+//!
+//! Or the same can be done with [`Configuration::builder`]:
+//! ```rust
+//! use ina219::configuration::{Configuration, Resolution};
+//! let conf = Configuration::builder()
+//!     .bus_resolution(Resolution::Avg128)
+//!     .shunt_resolution(Resolution::Avg128)
+//!     .build();
+//! ```
 
 use crate::register;
 use core::ops::{RangeInclusive, RangeToInclusive};
@@ -22,6 +31,8 @@ use register::{ReadRegister, Register, WriteRegister};
 /// If set to `Reset` all registers are set to their defaults. The flag is cleared after the reset
 /// was performed. So this should always read as `Run`.
 #[derive(Default, Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[repr(u8)]
 pub enum Reset {
     /// Continue normal operation
@@ -54,6 +65,8 @@ impl Reset {
 
 /// Measurement range for the bus voltage
 #[derive(Default, Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[repr(u8)]
 pub enum BusVoltageRange {
     /// Maximum bus voltage of 16V
@@ -63,6 +76,13 @@ pub enum BusVoltageRange {
     Fsr32v = 1,
 }
 
+/// Prints the full scale range, e.g. `32V`
+impl core::fmt::Display for BusVoltageRange {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}V", self.range_v().end)
+    }
+}
+
 impl BusVoltageRange {
     const SHIFT: u8 = 13;
     const MASK: u16 = 1;
@@ -76,6 +96,40 @@ impl BusVoltageRange {
         }
     }
 
+    /// The smallest range that can still measure a bus voltage of `volts`
+    ///
+    /// Returns `None` if the voltage exceeds even the largest range.
+    ///
+    /// # Example
+    /// ```rust
+    /// use ina219::configuration::BusVoltageRange;
+    ///
+    /// assert_eq!(BusVoltageRange::smallest_fitting(12), Some(BusVoltageRange::Fsr16v));
+    /// assert_eq!(BusVoltageRange::smallest_fitting(24), Some(BusVoltageRange::Fsr32v));
+    /// assert_eq!(BusVoltageRange::smallest_fitting(33), None);
+    /// ```
+    #[must_use]
+    pub const fn smallest_fitting(volts: u16) -> Option<Self> {
+        match volts {
+            0..=16 => Some(BusVoltageRange::Fsr16v),
+            17..=32 => Some(BusVoltageRange::Fsr32v),
+            _ => None,
+        }
+    }
+
+    /// Create the range from the bits of its field in the configuration register
+    ///
+    /// `bits` is the value of the field shifted down to the lowest bits, so `0` or `1`.
+    ///
+    /// # Errors
+    /// Returns an error if `bits` does not fit into the field.
+    pub const fn from_bits(bits: u16) -> Result<Self, InvalidBits> {
+        if bits > Self::MASK {
+            return Err(InvalidBits { bits });
+        }
+        Ok(Self::from_register(bits << Self::SHIFT))
+    }
+
     #[must_use]
     const fn from_register(reg: u16) -> Self {
         match (reg >> Self::SHIFT) & Self::MASK {
@@ -98,6 +152,8 @@ impl BusVoltageRange {
 /// This sets the value for the [PGA](https://en.wikipedia.org/wiki/Programmable-gain_amplifier) and
 /// thus the maximum shunt voltage that can be measured.
 #[derive(Default, Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[repr(u8)]
 pub enum ShuntVoltageRange {
     /// Range of ±40mV, gain of 1
@@ -111,6 +167,13 @@ pub enum ShuntVoltageRange {
     Fsr320mv = 3,
 }
 
+/// Prints the full scale range, e.g. `±320mV`
+impl core::fmt::Display for ShuntVoltageRange {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "±{}mV", self.range_mv().end())
+    }
+}
+
 impl ShuntVoltageRange {
     const SHIFT: u8 = 11;
     const MASK: u16 = 0b11;
@@ -126,6 +189,80 @@ impl ShuntVoltageRange {
         }
     }
 
+    /// Divisor of the programmable gain amplifier (PGA) for this range
+    ///
+    /// The PGA divides the shunt voltage by this value before it is sampled, which scales the 40mV
+    /// range of the ADC up to the full scale of this range.
+    ///
+    /// # Example
+    /// ```rust
+    /// use ina219::configuration::ShuntVoltageRange;
+    ///
+    /// assert_eq!(ShuntVoltageRange::Fsr160mv.pga_divisor(), 4);
+    /// ```
+    #[must_use]
+    pub const fn pga_divisor(self) -> u8 {
+        match self {
+            ShuntVoltageRange::Fsr40mv => 1,
+            ShuntVoltageRange::Fsr80mv => 2,
+            ShuntVoltageRange::Fsr160mv => 4,
+            ShuntVoltageRange::Fsr320mv => 8,
+        }
+    }
+
+    /// Numerator of the PGA gain, which is always 1
+    ///
+    /// See [`Self::gain_denominator`].
+    #[must_use]
+    #[allow(clippy::unused_self)] // Kept for symmetry with gain_denominator
+    pub const fn gain_numerator(self) -> u8 {
+        1
+    }
+
+    /// Denominator of the PGA gain, the gain is `gain_numerator / gain_denominator`
+    ///
+    /// This is the same as [`Self::pga_divisor`].
+    #[must_use]
+    pub const fn gain_denominator(self) -> u8 {
+        self.pga_divisor()
+    }
+
+    /// The smallest range that can still measure a shunt voltage of `millivolts`
+    ///
+    /// The ranges are symmetric so only the magnitude of `millivolts` is considered. Returns `None`
+    /// if the voltage exceeds even the largest range.
+    ///
+    /// # Example
+    /// ```rust
+    /// use ina219::configuration::ShuntVoltageRange;
+    ///
+    /// assert_eq!(ShuntVoltageRange::smallest_fitting(-50), Some(ShuntVoltageRange::Fsr80mv));
+    /// assert_eq!(ShuntVoltageRange::smallest_fitting(500), None);
+    /// ```
+    #[must_use]
+    pub const fn smallest_fitting(millivolts: i16) -> Option<Self> {
+        match millivolts.unsigned_abs() {
+            0..=40 => Some(ShuntVoltageRange::Fsr40mv),
+            41..=80 => Some(ShuntVoltageRange::Fsr80mv),
+            81..=160 => Some(ShuntVoltageRange::Fsr160mv),
+            161..=320 => Some(ShuntVoltageRange::Fsr320mv),
+            _ => None,
+        }
+    }
+
+    /// Create the range from the bits of its field in the configuration register
+    ///
+    /// `bits` is the value of the field shifted down to the lowest bits, so in `0..=3`.
+    ///
+    /// # Errors
+    /// Returns an error if `bits` does not fit into the field.
+    pub const fn from_bits(bits: u16) -> Result<Self, InvalidBits> {
+        if bits > Self::MASK {
+            return Err(InvalidBits { bits });
+        }
+        Ok(Self::from_register(bits << Self::SHIFT))
+    }
+
     #[must_use]
     const fn from_register(reg: u16) -> Self {
         match (reg >> Self::SHIFT) & Self::MASK {
@@ -149,6 +286,8 @@ impl ShuntVoltageRange {
 ///
 /// This sets resolution which is used when sampling the voltages.
 #[derive(Default, Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[repr(u8)]
 pub enum Resolution {
     /// Single 9 bit sample
@@ -176,11 +315,63 @@ pub enum Resolution {
     Avg128 = 0b1111,
 }
 
+/// Prints the resolution of a single sample or the number of averaged samples, e.g. `12bit` or
+/// `128x avg`
+impl core::fmt::Display for Resolution {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Resolution::Res9Bit => write!(f, "9bit"),
+            Resolution::Res10Bit => write!(f, "10bit"),
+            Resolution::Res11Bit => write!(f, "11bit"),
+            Resolution::Res12Bit => write!(f, "12bit"),
+            _ => write!(f, "{}x avg", self.sample_count()),
+        }
+    }
+}
+
 impl Resolution {
+    /// All resolutions, ordered from the fastest to the most precise
+    ///
+    /// # Example
+    /// ```rust
+    /// use ina219::configuration::Resolution;
+    ///
+    /// for res in Resolution::ALL {
+    ///     println!("{res:?}: {} samples in {}µs", res.sample_count(), res.conversion_time_us());
+    /// }
+    /// ```
+    pub const ALL: [Resolution; 11] = [
+        Resolution::Res9Bit,
+        Resolution::Res10Bit,
+        Resolution::Res11Bit,
+        Resolution::Res12Bit,
+        Resolution::Avg2,
+        Resolution::Avg4,
+        Resolution::Avg8,
+        Resolution::Avg16,
+        Resolution::Avg32,
+        Resolution::Avg64,
+        Resolution::Avg128,
+    ];
+
     const SHIFT_BUS: u8 = 7;
     const SHIFT_SHUNT: u8 = 3;
     const MASK: u16 = 0b1111;
 
+    /// Create the resolution from the bits of its field in the configuration register
+    ///
+    /// `bits` is the value of the field shifted down to the lowest bits, so in `0..=15`. Like the
+    /// device, several bit patterns map to the same 9 to 12 bit resolutions.
+    ///
+    /// # Errors
+    /// Returns an error if `bits` does not fit into the field.
+    pub const fn from_bits(bits: u16) -> Result<Self, InvalidBits> {
+        if bits > Self::MASK {
+            return Err(InvalidBits { bits });
+        }
+        Ok(Self::from_register::<0>(bits))
+    }
+
     #[must_use]
     const fn from_register<const SHIFT: u8>(reg: u16) -> Self {
         match (reg >> SHIFT) & Self::MASK {
@@ -226,6 +417,60 @@ impl Resolution {
         self.apply_to_reg::<{ Self::SHIFT_SHUNT }>(reg)
     }
 
+    /// Number of samples that are averaged for a single measurement
+    ///
+    /// # Example
+    /// ```rust
+    /// use ina219::configuration::Resolution;
+    ///
+    /// assert_eq!(Resolution::Res9Bit.sample_count(), 1);
+    /// assert_eq!(Resolution::Avg16.sample_count(), 16);
+    /// ```
+    #[must_use]
+    pub const fn sample_count(self) -> u8 {
+        match self {
+            Resolution::Res9Bit
+            | Resolution::Res10Bit
+            | Resolution::Res11Bit
+            | Resolution::Res12Bit => 1,
+            Resolution::Avg2 => 2,
+            Resolution::Avg4 => 4,
+            Resolution::Avg8 => 8,
+            Resolution::Avg16 => 16,
+            Resolution::Avg32 => 32,
+            Resolution::Avg64 => 64,
+            Resolution::Avg128 => 128,
+        }
+    }
+
+    /// Check if this resolution averages multiple samples instead of taking a single sample
+    ///
+    /// # Example
+    /// ```rust
+    /// use ina219::configuration::Resolution;
+    ///
+    /// assert!(Resolution::Avg128.is_averaged());
+    /// assert!(!Resolution::Res12Bit.is_averaged());
+    /// ```
+    #[must_use]
+    pub const fn is_averaged(self) -> bool {
+        self.sample_count() > 1
+    }
+
+    /// Check if this resolution averages multiple samples, same as [`Resolution::is_averaged`]
+    ///
+    /// # Example
+    /// ```rust
+    /// use ina219::configuration::Resolution;
+    ///
+    /// assert!(Resolution::Avg128.is_averaging());
+    /// assert!(!Resolution::Res12Bit.is_averaging());
+    /// ```
+    #[must_use]
+    pub const fn is_averaging(self) -> bool {
+        self.is_averaged()
+    }
+
     /// Conversion time in µs when this resolution is active
     ///
     /// Values according to Table 5 in the datasheet.
@@ -249,6 +494,8 @@ impl Resolution {
 
 /// Which signals are measured during a conversion
 #[derive(Default, Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[repr(u8)]
 pub enum MeasuredSignals {
     /// Only the shunt voltage is measured
@@ -260,7 +507,30 @@ pub enum MeasuredSignals {
     ShutAndBusVoltage = 3,
 }
 
+/// Prints the measured voltages, e.g. `shunt+bus`
+impl core::fmt::Display for MeasuredSignals {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            MeasuredSignals::ShuntVoltage => write!(f, "shunt"),
+            MeasuredSignals::BusVoltage => write!(f, "bus"),
+            MeasuredSignals::ShutAndBusVoltage => write!(f, "shunt+bus"),
+        }
+    }
+}
+
 impl MeasuredSignals {
+    /// Check if the shunt voltage is part of these signals
+    #[must_use]
+    pub const fn includes_shunt_voltage(self) -> bool {
+        matches!(self, Self::ShuntVoltage | Self::ShutAndBusVoltage)
+    }
+
+    /// Check if the bus voltage is part of these signals
+    #[must_use]
+    pub const fn includes_bus_voltage(self) -> bool {
+        matches!(self, Self::BusVoltage | Self::ShutAndBusVoltage)
+    }
+
     #[must_use]
     const fn from_bits_wrapping(bits: u16) -> Self {
         match bits & 0b11 {
@@ -277,6 +547,8 @@ impl MeasuredSignals {
 
 /// Operation mode of the INA219
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[repr(u8)]
 pub enum OperatingMode {
     /// Reduce power usage and disable current into the input pins
@@ -291,10 +563,34 @@ pub enum OperatingMode {
     Continous(MeasuredSignals),
 }
 
+/// Prints the mode and the measured voltages, e.g. `continuous (shunt+bus)`
+impl core::fmt::Display for OperatingMode {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            OperatingMode::PowerDown => write!(f, "power down"),
+            OperatingMode::AdcOff => write!(f, "ADC off"),
+            OperatingMode::Triggered(signals) => write!(f, "triggered ({signals})"),
+            OperatingMode::Continous(signals) => write!(f, "continuous ({signals})"),
+        }
+    }
+}
+
 impl OperatingMode {
     const SHIFT: u8 = 0;
     const MASK: u16 = 0b111;
 
+    /// Create the mode from the bits of its field in the configuration register, the inverse of
+    /// [`Self::as_bits`]
+    ///
+    /// # Errors
+    /// Returns an error if `bits` does not fit into the field, so is larger than `0b111`.
+    pub const fn from_bits(bits: u16) -> Result<Self, InvalidBits> {
+        if bits > Self::MASK {
+            return Err(InvalidBits { bits });
+        }
+        Ok(Self::from_register(bits << Self::SHIFT))
+    }
+
     #[must_use]
     const fn from_register(reg: u16) -> Self {
         match (reg >> Self::SHIFT) & Self::MASK {
@@ -323,6 +619,44 @@ impl OperatingMode {
             OperatingMode::Continous(signals) => signals as u16 | 0b100,
         }
     }
+
+    /// Check if the device continuously measures signals in this mode
+    #[must_use]
+    pub const fn is_continuous(self) -> bool {
+        matches!(self, OperatingMode::Continous(_))
+    }
+
+    /// Check if the device measures signals once when triggered in this mode
+    #[must_use]
+    pub const fn is_triggered(self) -> bool {
+        matches!(self, OperatingMode::Triggered(_))
+    }
+
+    /// Check if the device does not measure any signals in this mode
+    ///
+    /// This is the case for both [`OperatingMode::PowerDown`] and [`OperatingMode::AdcOff`].
+    #[must_use]
+    pub const fn is_powered_down(self) -> bool {
+        matches!(self, OperatingMode::PowerDown | OperatingMode::AdcOff)
+    }
+
+    /// The signals measured in this mode, `None` if nothing is measured
+    ///
+    /// # Example
+    /// ```rust
+    /// use ina219::configuration::{MeasuredSignals, OperatingMode};
+    ///
+    /// let mode = OperatingMode::Triggered(MeasuredSignals::BusVoltage);
+    /// assert_eq!(mode.measured_signals(), Some(MeasuredSignals::BusVoltage));
+    /// assert_eq!(OperatingMode::AdcOff.measured_signals(), None);
+    /// ```
+    #[must_use]
+    pub const fn measured_signals(self) -> Option<MeasuredSignals> {
+        match self {
+            OperatingMode::PowerDown | OperatingMode::AdcOff => None,
+            OperatingMode::Triggered(signals) | OperatingMode::Continous(signals) => Some(signals),
+        }
+    }
 }
 
 impl Default for OperatingMode {
@@ -331,10 +665,49 @@ impl Default for OperatingMode {
     }
 }
 
+/// The bits do not fit into the field of the configuration register
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct InvalidBits {
+    bits: u16,
+}
+
+impl core::fmt::Display for InvalidBits {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Invalid bits for configuration field: {:#b}", self.bits)
+    }
+}
+
+impl core::error::Error for InvalidBits {}
+
+/// Implement `TryFrom<u16>` using `from_bits`
+macro_rules! impl_try_from_bits {
+    ($($field:ident),+) => {
+        $(
+            impl TryFrom<u16> for $field {
+                type Error = InvalidBits;
+
+                fn try_from(value: u16) -> Result<Self, Self::Error> {
+                    Self::from_bits(value)
+                }
+            }
+        )+
+    };
+}
+
+impl_try_from_bits!(
+    BusVoltageRange,
+    ShuntVoltageRange,
+    Resolution,
+    OperatingMode
+);
+
 /// Configuration register
 ///
 /// Configures the way the INA219 performs its measurements.
-#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct Configuration {
     /// Indicate to perform a reset or continue to run normally
     pub reset: Reset,
@@ -350,7 +723,61 @@ pub struct Configuration {
     pub operating_mode: OperatingMode,
 }
 
+impl Default for Configuration {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Prints a readable summary, e.g. `bus: 32V/12bit, shunt: ±320mV/12bit, mode: continuous (shunt+bus)`
+///
+/// If the reset bit is set `, reset` is appended.
+impl core::fmt::Display for Configuration {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "bus: {}/{}, shunt: {}/{}, mode: {}",
+            self.bus_voltage_range,
+            self.bus_resolution,
+            self.shunt_voltage_range,
+            self.shunt_resolution,
+            self.operating_mode
+        )?;
+        if self.reset == Reset::Reset {
+            write!(f, ", reset")?;
+        }
+        Ok(())
+    }
+}
+
 impl Configuration {
+    /// The configuration of the device after a reset
+    ///
+    /// Same as [`Configuration::default`], but usable in const contexts:
+    /// ```rust
+    /// use ina219::configuration::Configuration;
+    ///
+    /// static CONFIG: Configuration = Configuration::DEFAULT;
+    /// assert_eq!(CONFIG.as_bits(), 0b0011_1001_1001_1111);
+    /// ```
+    pub const DEFAULT: Self = Self {
+        reset: Reset::Run,
+        bus_voltage_range: BusVoltageRange::Fsr32v,
+        shunt_voltage_range: ShuntVoltageRange::Fsr320mv,
+        bus_resolution: Resolution::Res12Bit,
+        shunt_resolution: Resolution::Res12Bit,
+        operating_mode: OperatingMode::Continous(MeasuredSignals::ShutAndBusVoltage),
+    };
+
+    /// Start building a configuration from the default values
+    ///
+    /// See [`ConfigurationBuilder`].
+    pub const fn builder() -> ConfigurationBuilder {
+        ConfigurationBuilder {
+            config: Self::DEFAULT,
+        }
+    }
+
     /// Turn the bits describing the configuration into a `Register`
     #[must_use]
     pub const fn from_bits(reg: u16) -> Self {
@@ -393,7 +820,52 @@ impl Configuration {
         bits
     }
 
+    /// Check if two configurations configure the device in the same way
+    ///
+    /// The configuration register has several bit patterns that mean the same thing: the `ADC3`
+    /// bits are ignored for the 9 to 12 bit resolutions, bit 14 is unused and there are
+    /// three patterns that select a single 12 bit sample. Decoding with [`Self::from_bits`]
+    /// maps all of these onto the same `Configuration`. So configurations read from the device
+    /// can be compared without having to worry about these don't-care bits.
+    ///
+    /// # Example
+    /// ```rust
+    /// use ina219::configuration::Configuration;
+    ///
+    /// // Both patterns select 12 bit resolution for the bus voltage
+    /// let a = Configuration::from_bits(0b0011_1001_1001_1111);
+    /// let b = Configuration::from_bits(0b0011_1100_0001_1111);
+    /// assert!(a.semantically_eq(b));
+    /// ```
+    #[must_use]
+    pub const fn semantically_eq(self, other: Self) -> bool {
+        // `as_bits` creates a single canonical pattern for each configuration
+        self.as_bits() == other.as_bits()
+    }
+
+    /// Check if the register value `bits` configures the device in the same way as `self`
+    ///
+    /// Comparing [`Self::as_bits`] against a register value directly gives false mismatches for
+    /// the redundant encodings described in [`Self::semantically_eq`]. This decodes `bits` first,
+    /// so don't-care bits are ignored.
+    ///
+    /// # Example
+    /// ```rust
+    /// use ina219::configuration::Configuration;
+    ///
+    /// // Default configuration with the unused bit 14 set
+    /// assert!(Configuration::default().bits_equivalent(0b0111_1001_1001_1111));
+    /// ```
+    #[must_use]
+    pub const fn bits_equivalent(self, bits: u16) -> bool {
+        self.semantically_eq(Self::from_bits(bits))
+    }
+
     /// Total conversion time in µs with this configuration
+    ///
+    /// When both voltages are measured the conversion times of both resolutions are added. If only
+    /// one voltage is measured only its resolution is used. Returns `None` when the device is not
+    /// converting, i.e. in [`OperatingMode::PowerDown`] or [`OperatingMode::AdcOff`].
     #[must_use]
     pub const fn conversion_time_us(self) -> Option<u32> {
         let signals = match self.operating_mode {
@@ -411,7 +883,108 @@ impl Configuration {
         })
     }
 
+    /// Triggered conversions taking longer than this are reported by [`Self::validate`]
+    pub const LONG_TRIGGERED_CONVERSION_US: u32 = 100_000;
+
+    /// Check for combinations of settings that are valid but most likely a mistake
+    ///
+    /// This is purely advisory, the INA219 accepts any configuration.
+    ///
+    /// # Errors
+    /// Returns the first suspicious combination that was found as a [`ConfigWarning`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use ina219::configuration::{
+    ///     ConfigWarning, Configuration, MeasuredSignals, OperatingMode, Resolution,
+    /// };
+    ///
+    /// assert!(Configuration::default().validate().is_ok());
+    ///
+    /// let conf = Configuration {
+    ///     bus_resolution: Resolution::Avg128,
+    ///     operating_mode: OperatingMode::Continous(MeasuredSignals::ShuntVoltage),
+    ///     ..Configuration::default()
+    /// };
+    /// assert_eq!(conf.validate(), Err(ConfigWarning::UnusedBusResolution));
+    /// ```
+    pub fn validate(&self) -> Result<(), ConfigWarning> {
+        let without_reset = Self {
+            reset: Reset::Run,
+            ..*self
+        };
+        if self.reset == Reset::Reset && without_reset != Self::default() {
+            return Err(ConfigWarning::ResetDiscardsSettings);
+        }
+
+        let signals = match self.operating_mode {
+            OperatingMode::PowerDown | OperatingMode::AdcOff => return Ok(()),
+            OperatingMode::Triggered(s) | OperatingMode::Continous(s) => s,
+        };
+
+        match signals {
+            MeasuredSignals::ShuntVoltage if self.bus_resolution != Resolution::default() => {
+                return Err(ConfigWarning::UnusedBusResolution);
+            }
+            MeasuredSignals::BusVoltage if self.shunt_resolution != Resolution::default() => {
+                return Err(ConfigWarning::UnusedShuntResolution);
+            }
+            _ => {}
+        }
+
+        if let (OperatingMode::Triggered(_), Some(conversion_time_us)) =
+            (self.operating_mode, self.conversion_time_us())
+        {
+            if conversion_time_us > Self::LONG_TRIGGERED_CONVERSION_US {
+                return Err(ConfigWarning::LongTriggeredConversion { conversion_time_us });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Time in µs until the next conversion completes, `elapsed_us` µs after this configuration
+    /// was written
+    ///
+    /// In triggered mode a single conversion is performed, so this returns 0 once it finished. In
+    /// continuous mode conversions follow each other, so this is the time until the next one
+    /// completes, or 0 if one completed exactly now. Returns `None` when the device is not
+    /// converting.
+    #[must_use]
+    pub const fn time_until_ready_us(self, elapsed_us: u64) -> Option<u32> {
+        let Some(conversion_time_us) = self.conversion_time_us() else {
+            return None;
+        };
+        let conversion = conversion_time_us as u64;
+
+        let remaining = if elapsed_us < conversion {
+            conversion - elapsed_us
+        } else if self.operating_mode.is_continuous() {
+            (conversion - elapsed_us % conversion) % conversion
+        } else {
+            0
+        };
+
+        // The remaining time is at most the conversion time which fits into u32
+        #[allow(clippy::cast_possible_truncation)]
+        Some(remaining as u32)
+    }
+
+    /// Time until the next conversion completes as `std::time::Duration`
+    ///
+    /// See [`Self::time_until_ready_us`].
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn time_until_ready(self, elapsed: std::time::Duration) -> Option<std::time::Duration> {
+        let elapsed_us = u64::try_from(elapsed.as_micros()).unwrap_or(u64::MAX);
+        self.time_until_ready_us(elapsed_us)
+            .map(u64::from)
+            .map(std::time::Duration::from_micros)
+    }
+
     /// Total conversion time as `std::time::Duration`
+    ///
+    /// See [`Self::conversion_time_us`].
     #[cfg(feature = "std")]
     #[must_use]
     pub fn conversion_time(&self) -> Option<std::time::Duration> {
@@ -421,6 +994,116 @@ impl Configuration {
     }
 }
 
+/// Builder for a [`Configuration`]
+///
+/// Created by [`Configuration::builder`]. All settings that are not set keep their default value.
+///
+/// # Example
+/// ```rust
+/// use ina219::configuration::{BusVoltageRange, Configuration, Resolution};
+///
+/// let conf = Configuration::builder()
+///     .bus_voltage_range(BusVoltageRange::Fsr16v)
+///     .shunt_resolution(Resolution::Avg128)
+///     .build();
+///
+/// assert_eq!(conf.bus_voltage_range, BusVoltageRange::Fsr16v);
+/// assert_eq!(conf.bus_resolution, Resolution::default());
+/// ```
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+#[must_use]
+pub struct ConfigurationBuilder {
+    config: Configuration,
+}
+
+impl ConfigurationBuilder {
+    /// Set if a reset should be performed
+    pub const fn reset(mut self, reset: Reset) -> Self {
+        self.config.reset = reset;
+        self
+    }
+
+    /// Set the maximum measurement range for the bus voltage
+    pub const fn bus_voltage_range(mut self, range: BusVoltageRange) -> Self {
+        self.config.bus_voltage_range = range;
+        self
+    }
+
+    /// Set the maximum measurement range for the shunt voltage
+    pub const fn shunt_voltage_range(mut self, range: ShuntVoltageRange) -> Self {
+        self.config.shunt_voltage_range = range;
+        self
+    }
+
+    /// Set the resolution / averaging mode for the bus voltage measurement
+    pub const fn bus_resolution(mut self, resolution: Resolution) -> Self {
+        self.config.bus_resolution = resolution;
+        self
+    }
+
+    /// Set the resolution / averaging mode for the shunt voltage measurement
+    pub const fn shunt_resolution(mut self, resolution: Resolution) -> Self {
+        self.config.shunt_resolution = resolution;
+        self
+    }
+
+    /// Set which signals to measure and if continuous or triggered operation is used
+    pub const fn operating_mode(mut self, mode: OperatingMode) -> Self {
+        self.config.operating_mode = mode;
+        self
+    }
+
+    /// Create the [`Configuration`]
+    #[must_use]
+    pub const fn build(self) -> Configuration {
+        self.config
+    }
+}
+
+/// Suspicious combinations of settings found by [`Configuration::validate`]
+///
+/// These settings are all valid for the INA219, but are likely not what was intended.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ConfigWarning {
+    /// The reset bit is set, so all other settings are discarded and replaced by the defaults
+    ResetDiscardsSettings,
+    /// Only the shunt voltage is measured, so the bus resolution has no effect
+    UnusedBusResolution,
+    /// Only the bus voltage is measured, so the shunt resolution has no effect
+    UnusedShuntResolution,
+    /// A single triggered conversion takes longer than [`Configuration::LONG_TRIGGERED_CONVERSION_US`]
+    LongTriggeredConversion {
+        /// Total conversion time in µs
+        conversion_time_us: u32,
+    },
+}
+
+impl core::fmt::Display for ConfigWarning {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::ResetDiscardsSettings => {
+                write!(f, "Reset is set, all other settings are discarded")
+            }
+            Self::UnusedBusResolution => {
+                write!(
+                    f,
+                    "Bus resolution is set but the bus voltage is not measured"
+                )
+            }
+            Self::UnusedShuntResolution => {
+                write!(
+                    f,
+                    "Shunt resolution is set but the shunt voltage is not measured"
+                )
+            }
+            Self::LongTriggeredConversion { conversion_time_us } => write!(
+                f,
+                "A triggered conversion takes {conversion_time_us} µs to complete"
+            ),
+        }
+    }
+}
+
 impl Register for Configuration {
     const ADDRESS: u8 = 0;
 }
@@ -452,6 +1135,45 @@ mod tests {
         );
     }
 
+    #[test]
+    fn display() {
+        assert_eq!(
+            Configuration::DEFAULT.to_string(),
+            "bus: 32V/12bit, shunt: ±320mV/12bit, mode: continuous (shunt+bus)"
+        );
+
+        let conf = Configuration {
+            reset: Reset::Reset,
+            bus_voltage_range: BusVoltageRange::Fsr16v,
+            shunt_voltage_range: ShuntVoltageRange::Fsr40mv,
+            bus_resolution: Resolution::Res9Bit,
+            shunt_resolution: Resolution::Avg128,
+            operating_mode: OperatingMode::Triggered(MeasuredSignals::BusVoltage),
+        };
+        assert_eq!(
+            conf.to_string(),
+            "bus: 16V/9bit, shunt: ±40mV/128x avg, mode: triggered (bus), reset"
+        );
+        assert_eq!(OperatingMode::PowerDown.to_string(), "power down");
+        assert_eq!(OperatingMode::AdcOff.to_string(), "ADC off");
+    }
+
+    #[test]
+    fn default_matches_field_defaults() {
+        assert_eq!(
+            Configuration::DEFAULT,
+            Configuration {
+                reset: Reset::default(),
+                bus_voltage_range: BusVoltageRange::default(),
+                shunt_voltage_range: ShuntVoltageRange::default(),
+                bus_resolution: Resolution::default(),
+                shunt_resolution: Resolution::default(),
+                operating_mode: OperatingMode::default(),
+            }
+        );
+        assert_eq!(Configuration::builder().build(), Configuration::DEFAULT);
+    }
+
     #[test]
     fn is_inverse() {
         // We can not directly check if the same bit pattern is created because some patterns (like
@@ -474,4 +1196,345 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn validate() {
+        assert_eq!(Configuration::default().validate(), Ok(()));
+
+        let reset = Configuration {
+            reset: Reset::Reset,
+            ..Configuration::default()
+        };
+        assert_eq!(reset.validate(), Ok(()));
+
+        let reset = Configuration {
+            bus_voltage_range: BusVoltageRange::Fsr16v,
+            ..reset
+        };
+        assert_eq!(reset.validate(), Err(ConfigWarning::ResetDiscardsSettings));
+
+        let bus_only = Configuration {
+            shunt_resolution: Resolution::Avg2,
+            operating_mode: OperatingMode::Continous(MeasuredSignals::BusVoltage),
+            ..Configuration::default()
+        };
+        assert_eq!(
+            bus_only.validate(),
+            Err(ConfigWarning::UnusedShuntResolution)
+        );
+
+        let triggered = Configuration {
+            bus_resolution: Resolution::Avg128,
+            shunt_resolution: Resolution::Avg128,
+            operating_mode: OperatingMode::Triggered(MeasuredSignals::ShutAndBusVoltage),
+            ..Configuration::default()
+        };
+        assert_eq!(
+            triggered.validate(),
+            Err(ConfigWarning::LongTriggeredConversion {
+                conversion_time_us: 136_200
+            })
+        );
+
+        let continuous = Configuration {
+            operating_mode: OperatingMode::Continous(MeasuredSignals::ShutAndBusVoltage),
+            ..triggered
+        };
+        assert_eq!(continuous.validate(), Ok(()));
+    }
+
+    #[test]
+    fn semantically_eq_ignores_dont_care_bits() {
+        let reference = Configuration::default();
+        let reset_value = 0b0011_1001_1001_1111;
+
+        // Unused bit 14 and ADC3 bits for 12 bit resolution
+        for dont_care in [
+            0b0100_0000_0000_0000,
+            0b0000_0010_0000_0000,
+            0b0000_0000_0010_0000,
+        ] {
+            let read = Configuration::from_bits(reset_value | dont_care);
+            assert!(read.semantically_eq(reference));
+            assert_eq!(read, reference);
+        }
+
+        // The three patterns for 12 bit resolution
+        for pattern in [0b0011, 0b0111, 0b1000] {
+            let bits = (reset_value & !(0b1111 << 7)) | (pattern << 7);
+            assert!(Configuration::from_bits(bits).semantically_eq(reference));
+        }
+
+        let different = Configuration {
+            bus_resolution: Resolution::Res9Bit,
+            ..reference
+        };
+        assert!(!different.semantically_eq(reference));
+    }
+
+    #[test]
+    fn time_until_ready() {
+        // Default configuration converts both voltages with 12 bit resolution, 532µs each
+        let continuous = Configuration::default();
+        assert_eq!(continuous.time_until_ready_us(0), Some(1_064));
+        assert_eq!(continuous.time_until_ready_us(64), Some(1_000));
+        assert_eq!(continuous.time_until_ready_us(1_064), Some(0));
+        assert_eq!(continuous.time_until_ready_us(1_100), Some(1_028));
+
+        let triggered = Configuration {
+            operating_mode: OperatingMode::Triggered(MeasuredSignals::ShutAndBusVoltage),
+            ..continuous
+        };
+        assert_eq!(triggered.time_until_ready_us(64), Some(1_000));
+        assert_eq!(triggered.time_until_ready_us(1_100), Some(0));
+
+        let off = Configuration {
+            operating_mode: OperatingMode::PowerDown,
+            ..continuous
+        };
+        assert_eq!(off.time_until_ready_us(0), None);
+    }
+
+    #[test]
+    fn bits_equivalent() {
+        let reference = Configuration::default();
+        let reset_value = 0b0011_1001_1001_1111;
+
+        assert!(reference.bits_equivalent(reset_value));
+        assert!(reference.bits_equivalent(reset_value | 0b0100_0010_0010_0000));
+        // 12 bit resolution encoded as 0b1000 instead of 0b0011 for both voltages
+        assert!(reference.bits_equivalent(0b0011_1100_0100_0111));
+        assert!(!reference.bits_equivalent(reset_value & !0b0010_0000_0000_0000));
+    }
+
+    #[test]
+    fn builder() {
+        assert_eq!(Configuration::builder().build(), Configuration::default());
+
+        let built = Configuration::builder()
+            .reset(Reset::Reset)
+            .bus_voltage_range(BusVoltageRange::Fsr16v)
+            .shunt_voltage_range(ShuntVoltageRange::Fsr80mv)
+            .bus_resolution(Resolution::Avg2)
+            .shunt_resolution(Resolution::Res9Bit)
+            .operating_mode(OperatingMode::AdcOff)
+            .build();
+
+        assert_eq!(
+            built,
+            Configuration {
+                reset: Reset::Reset,
+                bus_voltage_range: BusVoltageRange::Fsr16v,
+                shunt_voltage_range: ShuntVoltageRange::Fsr80mv,
+                bus_resolution: Resolution::Avg2,
+                shunt_resolution: Resolution::Res9Bit,
+                operating_mode: OperatingMode::AdcOff,
+            }
+        );
+    }
+
+    #[test]
+    fn conversion_time() {
+        let conf = Configuration {
+            bus_resolution: Resolution::Avg2,
+            shunt_resolution: Resolution::Res9Bit,
+            ..Configuration::default()
+        };
+        assert_eq!(conf.conversion_time_us(), Some(1_060 + 84));
+
+        for (signals, expected) in [
+            (MeasuredSignals::BusVoltage, 1_060),
+            (MeasuredSignals::ShuntVoltage, 84),
+        ] {
+            for mode in [
+                OperatingMode::Triggered(signals),
+                OperatingMode::Continous(signals),
+            ] {
+                let conf = Configuration {
+                    operating_mode: mode,
+                    ..conf
+                };
+                assert_eq!(conf.conversion_time_us(), Some(expected));
+            }
+        }
+
+        for mode in [OperatingMode::PowerDown, OperatingMode::AdcOff] {
+            let conf = Configuration {
+                operating_mode: mode,
+                ..conf
+            };
+            assert_eq!(conf.conversion_time_us(), None);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn conversion_time_duration() {
+        assert_eq!(
+            Configuration::default().conversion_time(),
+            Some(std::time::Duration::from_micros(1_064))
+        );
+    }
+
+    #[test]
+    fn smallest_fitting_shunt_range() {
+        use ShuntVoltageRange::*;
+
+        assert_eq!(ShuntVoltageRange::smallest_fitting(0), Some(Fsr40mv));
+        assert_eq!(ShuntVoltageRange::smallest_fitting(40), Some(Fsr40mv));
+        assert_eq!(ShuntVoltageRange::smallest_fitting(-40), Some(Fsr40mv));
+        assert_eq!(ShuntVoltageRange::smallest_fitting(41), Some(Fsr80mv));
+        assert_eq!(ShuntVoltageRange::smallest_fitting(80), Some(Fsr80mv));
+        assert_eq!(ShuntVoltageRange::smallest_fitting(81), Some(Fsr160mv));
+        assert_eq!(ShuntVoltageRange::smallest_fitting(160), Some(Fsr160mv));
+        assert_eq!(ShuntVoltageRange::smallest_fitting(161), Some(Fsr320mv));
+        assert_eq!(ShuntVoltageRange::smallest_fitting(320), Some(Fsr320mv));
+        assert_eq!(ShuntVoltageRange::smallest_fitting(-320), Some(Fsr320mv));
+        assert_eq!(ShuntVoltageRange::smallest_fitting(321), None);
+        assert_eq!(ShuntVoltageRange::smallest_fitting(i16::MIN), None);
+
+        for mv in -320..=320 {
+            let range = ShuntVoltageRange::smallest_fitting(mv).unwrap();
+            assert!(range.range_mv().contains(&mv));
+        }
+    }
+
+    #[test]
+    fn field_from_bits() {
+        assert_eq!(BusVoltageRange::from_bits(1), Ok(BusVoltageRange::Fsr32v));
+        assert!(BusVoltageRange::from_bits(2).is_err());
+
+        assert_eq!(
+            ShuntVoltageRange::try_from(2),
+            Ok(ShuntVoltageRange::Fsr160mv)
+        );
+        assert!(ShuntVoltageRange::try_from(4).is_err());
+
+        assert_eq!(Resolution::from_bits(0b1111), Ok(Resolution::Avg128));
+        assert_eq!(Resolution::from_bits(0b1000), Ok(Resolution::Res12Bit));
+        assert!(Resolution::from_bits(0b1_0000).is_err());
+        for res in Resolution::ALL {
+            assert_eq!(Resolution::try_from(res as u16), Ok(res));
+        }
+
+        for bits in 0..=0b111 {
+            assert_eq!(OperatingMode::from_bits(bits).unwrap().as_bits(), bits);
+        }
+        assert!(OperatingMode::try_from(0b1000).is_err());
+    }
+
+    #[test]
+    fn pga_gain() {
+        use ShuntVoltageRange::*;
+
+        for range in [Fsr40mv, Fsr80mv, Fsr160mv, Fsr320mv] {
+            // The gain maps the full scale range onto the 40mV of the ADC
+            let full_scale = *range.range_mv().end();
+            assert_eq!(
+                full_scale * i16::from(range.gain_numerator())
+                    / i16::from(range.gain_denominator()),
+                40
+            );
+            assert_eq!(range.pga_divisor(), range.gain_denominator());
+        }
+    }
+
+    #[test]
+    fn smallest_fitting_bus_range() {
+        assert_eq!(
+            BusVoltageRange::smallest_fitting(0),
+            Some(BusVoltageRange::Fsr16v)
+        );
+        assert_eq!(
+            BusVoltageRange::smallest_fitting(16),
+            Some(BusVoltageRange::Fsr16v)
+        );
+        assert_eq!(
+            BusVoltageRange::smallest_fitting(17),
+            Some(BusVoltageRange::Fsr32v)
+        );
+        assert_eq!(
+            BusVoltageRange::smallest_fitting(32),
+            Some(BusVoltageRange::Fsr32v)
+        );
+        assert_eq!(BusVoltageRange::smallest_fitting(33), None);
+    }
+
+    #[test]
+    fn sample_count() {
+        use Resolution::*;
+
+        for res in [Res9Bit, Res10Bit, Res11Bit, Res12Bit] {
+            assert_eq!(res.sample_count(), 1);
+            assert!(!res.is_averaged());
+            assert!(!res.is_averaging());
+        }
+
+        for (res, count) in [
+            (Avg2, 2),
+            (Avg4, 4),
+            (Avg8, 8),
+            (Avg16, 16),
+            (Avg32, 32),
+            (Avg64, 64),
+            (Avg128, 128),
+        ] {
+            assert_eq!(res.sample_count(), count);
+            assert!(res.is_averaged());
+            assert!(res.is_averaging());
+        }
+    }
+
+    #[test]
+    fn operating_mode_predicates() {
+        use MeasuredSignals::*;
+        use OperatingMode::*;
+
+        for mode in [PowerDown, AdcOff] {
+            assert!(mode.is_powered_down());
+            assert!(!mode.is_continuous());
+            assert!(!mode.is_triggered());
+            assert_eq!(mode.measured_signals(), None);
+        }
+
+        for signals in [ShuntVoltage, BusVoltage, ShutAndBusVoltage] {
+            let mode = Triggered(signals);
+            assert!(mode.is_triggered());
+            assert!(!mode.is_continuous());
+            assert!(!mode.is_powered_down());
+            assert_eq!(mode.measured_signals(), Some(signals));
+
+            let mode = Continous(signals);
+            assert!(mode.is_continuous());
+            assert!(!mode.is_triggered());
+            assert!(!mode.is_powered_down());
+            assert_eq!(mode.measured_signals(), Some(signals));
+        }
+    }
+
+    #[test]
+    fn all_resolutions() {
+        assert!(Resolution::ALL.windows(2).all(|w| w[0] < w[1]));
+        assert!(Resolution::ALL
+            .windows(2)
+            .all(|w| w[0].conversion_time_us() < w[1].conversion_time_us()));
+
+        for res in Resolution::ALL {
+            let reg = res.apply_to_bus_reg(0);
+            assert_eq!(Resolution::from_bus_register(reg), res);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_round_trip() {
+        let conf = Configuration::builder()
+            .bus_voltage_range(BusVoltageRange::Fsr16v)
+            .shunt_resolution(Resolution::Avg64)
+            .operating_mode(OperatingMode::Triggered(MeasuredSignals::BusVoltage))
+            .build();
+
+        let json = serde_json::to_string(&conf).unwrap();
+        assert_eq!(serde_json::from_str::<Configuration>(&json).unwrap(), conf);
+    }
 }
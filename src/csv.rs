@@ -0,0 +1,117 @@
+//! Log measurements as CSV
+//!
+//! [`Ina219CsvLogger`] wraps a calibrated driver and any [`Write`] and appends a row for each new
+//! measurement. The columns are:
+//!
+//! | Column         | Description                                      |
+//! |----------------|--------------------------------------------------|
+//! | `timestamp_us` | Time in µs since the logger was created          |
+//! | `bus_mv`       | Bus voltage in mV                                |
+//! | `shunt_uv`     | Shunt voltage in µV                              |
+//! | `current_ua`   | Current in µA                                    |
+//! | `power_uw`     | Power in µW                                      |
+
+use crate::calibration::IntCalibration;
+use crate::errors::MeasurementError;
+use crate::SyncIna219;
+use core::fmt::{Debug, Display, Formatter};
+use embedded_hal::i2c::I2c;
+use std::io::Write;
+use std::time::Instant;
+
+/// Header line written by [`Ina219CsvLogger::write_header`]
+pub const HEADER: &str = "timestamp_us,bus_mv,shunt_uv,current_ua,power_uw";
+
+/// Appends measurements of an INA219 as CSV rows to a writer
+pub struct Ina219CsvLogger<I2C, W> {
+    ina: SyncIna219<I2C, IntCalibration>,
+    writer: W,
+    start: Instant,
+}
+
+impl<I2C, W> Ina219CsvLogger<I2C, W>
+where
+    I2C: I2c,
+    W: Write,
+{
+    /// Create a new logger, timestamps are relative to the moment this is called
+    pub fn new(ina: SyncIna219<I2C, IntCalibration>, writer: W) -> Self {
+        Self {
+            ina,
+            writer,
+            start: Instant::now(),
+        }
+    }
+
+    /// Write the [`HEADER`] line
+    ///
+    /// # Errors
+    /// Returns an error if writing to the writer fails.
+    pub fn write_header(&mut self) -> std::io::Result<()> {
+        writeln!(self.writer, "{HEADER}")
+    }
+
+    /// Read the next measurement and append it as a row
+    ///
+    /// Returns `Ok(false)` if there was no new measurement, nothing is written in that case.
+    ///
+    /// # Errors
+    /// Returns an error if the measurement could not be read or writing to the writer fails.
+    pub fn log_measurement(&mut self) -> Result<bool, CsvLogError<I2C::Error>> {
+        let Some(m) = self
+            .ina
+            .next_measurement()
+            .map_err(CsvLogError::Measurement)?
+        else {
+            return Ok(false);
+        };
+
+        writeln!(
+            self.writer,
+            "{},{},{},{},{}",
+            self.start.elapsed().as_micros(),
+            m.bus_voltage.voltage_mv(),
+            m.shunt_voltage.shunt_voltage_uv(),
+            m.current.0,
+            m.power.0,
+        )
+        .map_err(CsvLogError::Io)?;
+
+        Ok(true)
+    }
+
+    /// Destroy the logger returning the driver and writer
+    pub fn destroy(self) -> (SyncIna219<I2C, IntCalibration>, W) {
+        (self.ina, self.writer)
+    }
+}
+
+/// Errors that can happen while logging a measurement
+#[derive(Debug)]
+pub enum CsvLogError<I2cErr> {
+    /// The measurement could not be read
+    Measurement(MeasurementError<I2cErr>),
+    /// Writing the row failed
+    Io(std::io::Error),
+}
+
+impl<E: Debug> Display for CsvLogError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Measurement(err) => write!(f, "Measurement error: {err}"),
+            Self::Io(err) => write!(f, "IO error: {err}"),
+        }
+    }
+}
+
+impl<I2cErr> core::error::Error for CsvLogError<I2cErr>
+where
+    I2cErr: Debug + core::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Measurement(err) => Some(err),
+            Self::Io(err) => Some(err),
+        }
+    }
+}
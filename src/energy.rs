@@ -0,0 +1,108 @@
+//! Integrate current and power over time
+//!
+//! [`EnergyAccumulator`] sums up the charge and energy of a sequence of measurements, e.g. for
+//! coulomb counting a battery.
+//!
+//! # Example
+//! ```rust
+//! use ina219::calibration::{MicroAmpere, MicroWatt};
+//! use ina219::energy::EnergyAccumulator;
+//! use std::time::Duration;
+//!
+//! let mut acc = EnergyAccumulator::new();
+//! // 1A at 5V for half an hour
+//! acc.add(MicroAmpere(1_000_000), MicroWatt(5_000_000), Duration::from_secs(1_800));
+//! assert_eq!(acc.charge_uah(), 500_000);
+//! assert_eq!(acc.energy_uwh(), 2_500_000);
+//! ```
+
+use crate::calibration::{MicroAmpere, MicroWatt};
+use std::time::Duration;
+
+/// Nanoseconds per hour
+const NS_PER_H: i128 = 3_600 * 1_000_000_000;
+
+/// Accumulates charge in µAh and energy in µWh
+///
+/// Internally the sums are kept in µA·ns and µW·ns, so no precision is lost between calls to
+/// [`Self::add`]. All arithmetic saturates instead of overflowing.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub struct EnergyAccumulator {
+    charge_ua_ns: i128,
+    energy_uw_ns: i128,
+}
+
+impl EnergyAccumulator {
+    /// Create an accumulator with no charge and energy
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            charge_ua_ns: 0,
+            energy_uw_ns: 0,
+        }
+    }
+
+    /// Add a measurement of `current` and `power` that lasted for `duration`
+    pub fn add(&mut self, current: MicroAmpere, power: MicroWatt, duration: Duration) {
+        let ns = i128::try_from(duration.as_nanos()).unwrap_or(i128::MAX);
+
+        self.charge_ua_ns = self
+            .charge_ua_ns
+            .saturating_add(i128::from(current.0).saturating_mul(ns));
+        self.energy_uw_ns = self
+            .energy_uw_ns
+            .saturating_add(i128::from(power.0).saturating_mul(ns));
+    }
+
+    /// Accumulated charge in µAh, truncated towards zero
+    #[must_use]
+    pub fn charge_uah(&self) -> i64 {
+        saturate(self.charge_ua_ns / NS_PER_H)
+    }
+
+    /// Accumulated energy in µWh, truncated towards zero
+    #[must_use]
+    pub fn energy_uwh(&self) -> i64 {
+        saturate(self.energy_uw_ns / NS_PER_H)
+    }
+
+    /// Reset the accumulated charge and energy to zero
+    pub const fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+fn saturate(value: i128) -> i64 {
+    i64::try_from(value).unwrap_or(if value < 0 { i64::MIN } else { i64::MAX })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_precision_between_adds() {
+        let mut acc = EnergyAccumulator::new();
+        // 3600 times 1µA for 1s is 1µAh, while each step alone truncates to 0
+        for _ in 0..3_600 {
+            acc.add(MicroAmpere(1), MicroWatt(2), Duration::from_secs(1));
+        }
+        assert_eq!(acc.charge_uah(), 1);
+        assert_eq!(acc.energy_uwh(), 2);
+
+        acc.add(MicroAmpere(-3), MicroWatt(0), Duration::from_secs(3_600));
+        assert_eq!(acc.charge_uah(), -2);
+
+        acc.reset();
+        assert_eq!(acc, EnergyAccumulator::default());
+    }
+
+    #[test]
+    fn saturates() {
+        let mut acc = EnergyAccumulator::new();
+        acc.add(MicroAmpere(i64::MAX), MicroWatt(i64::MIN), Duration::MAX);
+        acc.add(MicroAmpere(i64::MAX), MicroWatt(i64::MIN), Duration::MAX);
+        assert_eq!(acc.charge_uah(), i64::MAX);
+        assert_eq!(acc.energy_uwh(), i64::MIN);
+    }
+}
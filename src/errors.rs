@@ -2,16 +2,24 @@
 
 //! Errors that can be returned by the different functions
 
+use crate::calibration::MicroAmpere;
 use crate::configuration::{BusVoltageRange, Configuration, ShuntVoltageRange};
 use crate::measurements::{BusVoltage, Measurements, ShuntVoltage};
 use crate::register::RegisterName;
 use core::fmt;
 use core::fmt::{Debug, Display, Formatter};
+#[cfg(feature = "sync")]
+use embedded_hal::i2c::{Error as I2cErrorTrait, ErrorKind};
+#[cfg(all(not(feature = "sync"), feature = "async"))]
+use embedded_hal_async::i2c::{Error as I2cErrorTrait, ErrorKind};
 
 #[cfg(all(doc, feature = "sync"))]
 use crate::SyncIna219;
 
 /// Error returned in case the initialization fails
+///
+/// The `Debug`, `Display` and `Error` implementations only depend on `I2cErr`, not on the device.
+/// So this can be turned into a `Box<dyn Error>` with `?` whatever the type of the device is.
 #[cfg_attr(not(feature = "sync"), allow(rustdoc::broken_intra_doc_links))]
 pub struct InitializationError<I2c, I2cErr> {
     /// Reason why the initialization failed
@@ -37,8 +45,16 @@ impl<I2c, I2cErr: Debug> Debug for InitializationError<I2c, I2cErr> {
     }
 }
 
+#[cfg(feature = "defmt")]
+impl<I2c, I2cErr: defmt::Format> defmt::Format for InitializationError<I2c, I2cErr> {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(f, "InitializationError({})", self.reason);
+    }
+}
+
 /// Error conditions that can appear during initialization
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum InitializationErrorReason<I2cErr> {
     /// An I2C read or write failed
     I2cError(I2cErr),
@@ -76,44 +92,55 @@ impl<E> From<BusVoltageReadError<E>> for InitializationErrorReason<E> {
     }
 }
 
-#[cfg(feature = "std")]
-impl<I2c, I2cErr> std::error::Error for InitializationError<I2c, I2cErr>
+impl<I2c, I2cErr> core::error::Error for InitializationError<I2c, I2cErr>
 where
-    I2cErr: Debug + std::error::Error + 'static,
+    I2cErr: Debug + core::error::Error + 'static,
 {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
-        match &self.reason {
-            InitializationErrorReason::I2cError(err) => Some(err),
-            InitializationErrorReason::ConfigurationNotDefaultAfterReset
-            | InitializationErrorReason::BusVoltageOutOfRange
-            | InitializationErrorReason::RegisterNotZeroAfterReset(_)
-            | InitializationErrorReason::ShuntVoltageOutOfRange => None,
-        }
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        self.reason.source()
     }
 }
 
 impl<I2c, I2cErr: Debug> Display for InitializationError<I2c, I2cErr> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        match &self.reason {
-            InitializationErrorReason::I2cError(err) => write!(f, "I2C error: {err:?}"),
-            InitializationErrorReason::ConfigurationNotDefaultAfterReset => {
+        Display::fmt(&self.reason, f)
+    }
+}
+
+impl<I2cErr> core::error::Error for InitializationErrorReason<I2cErr>
+where
+    I2cErr: Debug + core::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::I2cError(err) => Some(err),
+            Self::ConfigurationNotDefaultAfterReset
+            | Self::BusVoltageOutOfRange
+            | Self::RegisterNotZeroAfterReset(_)
+            | Self::ShuntVoltageOutOfRange => None,
+        }
+    }
+}
+
+impl<I2cErr: Debug> Display for InitializationErrorReason<I2cErr> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::I2cError(err) => write!(f, "I2C error: {err:?}"),
+            Self::ConfigurationNotDefaultAfterReset => {
                 write!(f, "Configuration was not default after reset")
             }
-            InitializationErrorReason::RegisterNotZeroAfterReset(reg) => {
+            Self::RegisterNotZeroAfterReset(reg) => {
                 write!(f, "Register {reg:?} was not zero after reset")
             }
-            InitializationErrorReason::ShuntVoltageOutOfRange => {
-                write!(f, "Shunt voltage was out of range")
-            }
-            InitializationErrorReason::BusVoltageOutOfRange => {
-                write!(f, "Bus voltage was out of range")
-            }
+            Self::ShuntVoltageOutOfRange => write!(f, "Shunt voltage was out of range"),
+            Self::BusVoltageOutOfRange => write!(f, "Bus voltage was out of range"),
         }
     }
 }
 
 /// Errors that can happen when a measurement is read
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum MeasurementError<I2cErr> {
     /// An I2C read or write failed
     I2cError(I2cErr),
@@ -123,6 +150,10 @@ pub enum MeasurementError<I2cErr> {
     BusVoltageReadError(BusVoltageReadError<I2cErr>),
     /// The INA219 reported a math overflow for the given bus and shunt voltage
     MathOverflow(Measurements<(), ()>),
+    /// The INA219 is configured to not perform any conversions, so no new measurement will arrive
+    DeviceNotConverting,
+    /// No new measurement arrived within the expected time
+    ConversionTimeout,
 }
 
 impl<E> From<E> for MeasurementError<E> {
@@ -151,17 +182,16 @@ impl<E> From<BusVoltageReadError<E>> for MeasurementError<E> {
     }
 }
 
-#[cfg(feature = "std")]
-impl<I2cErr> std::error::Error for MeasurementError<I2cErr>
+impl<I2cErr> core::error::Error for MeasurementError<I2cErr>
 where
-    I2cErr: Debug + std::error::Error + 'static,
+    I2cErr: Debug + core::error::Error + 'static,
 {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
         match self {
             Self::I2cError(err) => Some(err),
             Self::ShuntVoltageReadError(err) => Some(err),
             Self::BusVoltageReadError(err) => Some(err),
-            Self::MathOverflow(_) => None,
+            Self::MathOverflow(_) | Self::DeviceNotConverting | Self::ConversionTimeout => None,
         }
     }
 }
@@ -180,12 +210,15 @@ impl<I2cErr: Debug> Display for MeasurementError<I2cErr> {
                 f,
                 "Math overflow for shunt voltage {shunt_voltage:?} and bus voltage {bus_voltage:?}"
             ),
+            Self::DeviceNotConverting => write!(f, "Device is not performing any conversions"),
+            Self::ConversionTimeout => write!(f, "Timed out waiting for a conversion"),
         }
     }
 }
 
 /// Errors that can happen when the shunt voltage is read
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum ShuntVoltageReadError<I2cErr> {
     /// THE I2C read failed
     I2cError(I2cErr),
@@ -216,12 +249,11 @@ impl<E: Debug> Display for ShuntVoltageReadError<E> {
     }
 }
 
-#[cfg(feature = "std")]
-impl<I2cErr> std::error::Error for ShuntVoltageReadError<I2cErr>
+impl<I2cErr> core::error::Error for ShuntVoltageReadError<I2cErr>
 where
-    I2cErr: Debug + std::error::Error + 'static,
+    I2cErr: Debug + core::error::Error + 'static,
 {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
         match self {
             Self::I2cError(err) => Some(err),
             Self::ShuntVoltageOutOfRange { .. } => None,
@@ -231,6 +263,7 @@ where
 
 /// Errors that can happen when the bus voltage is read
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum BusVoltageReadError<I2cErr> {
     /// The I2C read failed
     I2cError(I2cErr),
@@ -261,12 +294,11 @@ impl<E: Debug> Display for BusVoltageReadError<E> {
     }
 }
 
-#[cfg(feature = "std")]
-impl<I2cErr> std::error::Error for BusVoltageReadError<I2cErr>
+impl<I2cErr> core::error::Error for BusVoltageReadError<I2cErr>
 where
-    I2cErr: Debug + std::error::Error + 'static,
+    I2cErr: Debug + core::error::Error + 'static,
 {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
         match self {
             Self::I2cError(err) => Some(err),
             Self::BusVoltageOutOfRange { .. } => None,
@@ -276,6 +308,7 @@ where
 
 /// Errors that can happen when the configuration is read
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum ConfigurationReadError<I2cErr> {
     /// The I2C read failed
     I2cError(I2cErr),
@@ -306,15 +339,184 @@ impl<E: Debug> Display for ConfigurationReadError<E> {
     }
 }
 
-#[cfg(feature = "std")]
-impl<I2cErr> std::error::Error for ConfigurationReadError<I2cErr>
+impl<I2cErr> core::error::Error for ConfigurationReadError<I2cErr>
 where
-    I2cErr: Debug + std::error::Error + 'static,
+    I2cErr: Debug + core::error::Error + 'static,
 {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
         match self {
             Self::I2cError(err) => Some(err),
             Self::ConfigurationMismatch { .. } => None,
         }
     }
 }
+
+/// Errors that can happen when configuration and calibration are written and verified together
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ProvisionError<I2cErr> {
+    /// An I2C read or write failed
+    I2cError(I2cErr),
+    /// The configuration read back from the device did not match the written configuration
+    ConfigurationMismatch {
+        /// Configuration read from the device
+        read: Configuration,
+        /// Configuration written to the device
+        written: Configuration,
+    },
+    /// The calibration read back from the device did not match the written calibration
+    CalibrationMismatch {
+        /// Value read from the calibration register
+        read: u16,
+        /// Value written to the calibration register
+        written: u16,
+    },
+}
+
+impl<E> From<E> for ProvisionError<E> {
+    fn from(value: E) -> Self {
+        Self::I2cError(value)
+    }
+}
+
+impl<E: Debug> Display for ProvisionError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::I2cError(err) => write!(f, "I2C error: {err:?}"),
+            Self::ConfigurationMismatch { read, written } => write!(
+                f,
+                "Configuration read from device {read:?} did not match written configuration {written:?}",
+            ),
+            Self::CalibrationMismatch { read, written } => write!(
+                f,
+                "Calibration read from device {read:#06x} did not match written calibration {written:#06x}",
+            ),
+        }
+    }
+}
+
+impl<I2cErr> core::error::Error for ProvisionError<I2cErr>
+where
+    I2cErr: Debug + core::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::I2cError(err) => Some(err),
+            Self::ConfigurationMismatch { .. } | Self::CalibrationMismatch { .. } => None,
+        }
+    }
+}
+
+/// Errors that can happen when the shunt voltage range is changed while keeping the calibration
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum RangeCalibrationError<I2cErr> {
+    /// An I2C read or write failed
+    I2cError(I2cErr),
+    /// The largest current of the new range is well below the full scale of the calibration
+    RangeClipsCalibration {
+        /// Largest current that can be measured with the new range
+        range_max: MicroAmpere,
+        /// Largest current that can be represented with the calibration
+        calibration_max: MicroAmpere,
+    },
+}
+
+impl<E> From<E> for RangeCalibrationError<E> {
+    fn from(value: E) -> Self {
+        Self::I2cError(value)
+    }
+}
+
+impl<E: Debug> Display for RangeCalibrationError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::I2cError(err) => write!(f, "I2C error: {err:?}"),
+            Self::RangeClipsCalibration {
+                range_max,
+                calibration_max,
+            } => write!(
+                f,
+                "Range can only measure up to {range_max} but calibration goes up to {calibration_max}"
+            ),
+        }
+    }
+}
+
+impl<I2cErr> core::error::Error for RangeCalibrationError<I2cErr>
+where
+    I2cErr: Debug + core::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::I2cError(err) => Some(err),
+            Self::RangeClipsCalibration { .. } => None,
+        }
+    }
+}
+
+/// Add `i2c_error_kind` to error enums with an `I2cError` variant
+#[cfg(any(feature = "sync", feature = "async"))]
+macro_rules! impl_i2c_error_kind {
+    ($($error:ident),+) => {
+        $(
+            impl<E: I2cErrorTrait> $error<E> {
+                /// Get the kind of the underlying I2C error, or `None` if this is not an I2C error
+                pub fn i2c_error_kind(&self) -> Option<ErrorKind> {
+                    if let Self::I2cError(err) = self {
+                        Some(err.kind())
+                    } else {
+                        None
+                    }
+                }
+            }
+        )+
+    };
+}
+
+#[cfg(any(feature = "sync", feature = "async"))]
+impl_i2c_error_kind!(
+    InitializationErrorReason,
+    MeasurementError,
+    ShuntVoltageReadError,
+    BusVoltageReadError,
+    ConfigurationReadError,
+    ProvisionError,
+    RangeCalibrationError
+);
+
+#[cfg(any(feature = "sync", feature = "async"))]
+impl<I2c, E: I2cErrorTrait> InitializationError<I2c, E> {
+    /// Get the kind of the underlying I2C error, or `None` if this is not an I2C error
+    pub fn i2c_error_kind(&self) -> Option<ErrorKind> {
+        self.reason.i2c_error_kind()
+    }
+}
+
+#[cfg(all(test, feature = "sync"))]
+mod tests {
+    use super::*;
+    use embedded_hal::i2c::NoAcknowledgeSource;
+
+    #[test]
+    fn i2c_error_kind() {
+        let nack = ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address);
+
+        assert_eq!(
+            MeasurementError::I2cError(nack).i2c_error_kind(),
+            Some(nack)
+        );
+        assert_eq!(
+            MeasurementError::<ErrorKind>::ConversionTimeout.i2c_error_kind(),
+            None
+        );
+        assert_eq!(
+            BusVoltageReadError::I2cError(ErrorKind::ArbitrationLoss).i2c_error_kind(),
+            Some(ErrorKind::ArbitrationLoss)
+        );
+        assert_eq!(
+            InitializationError::new(ErrorKind::Bus, ()).i2c_error_kind(),
+            Some(ErrorKind::Bus)
+        );
+    }
+}
@@ -11,16 +11,75 @@ pub mod errors;
 pub mod measurements;
 
 mod register;
+pub use register::RegisterName;
 
 #[cfg(feature = "async")]
 mod r#async;
 #[cfg(feature = "async")]
 pub use r#async::INA219 as AsyncIna219;
 
+/// The async driver
+///
+/// The blocking [`SyncIna219`] is generated from the same source, so both drivers have the same
+/// methods with the same signatures. The only difference is that the async methods need to be
+/// `.await`ed.
+#[cfg(feature = "async")]
+#[cfg_attr(not(feature = "sync"), allow(rustdoc::broken_intra_doc_links))]
+pub mod asynch {
+    pub use crate::r#async::INA219;
+}
+#[cfg(feature = "stream")]
+pub mod stream;
+#[cfg(feature = "async")]
+pub mod timeout;
+
 #[cfg(feature = "sync")]
 mod sync;
 #[cfg(feature = "sync")]
 pub use sync::INA219 as SyncIna219;
+#[cfg(feature = "sync")]
+mod blocking;
+#[cfg(feature = "sync")]
+pub use blocking::{scan, MeasurementIter};
+
+/// Options for how the driver brings the device into a known state when it is opened
+///
+/// Used by [`SyncIna219::new_with_options`] and [`AsyncIna219::new_with_options`], the other
+/// constructors use [`InitOptions::DEFAULT`].
+#[cfg(any(feature = "sync", feature = "async"))]
+#[cfg_attr(
+    not(all(feature = "sync", feature = "async")),
+    allow(rustdoc::broken_intra_doc_links)
+)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct InitOptions {
+    /// How often the configuration is read again while waiting for the reset to finish
+    ///
+    /// Some INA219 clones take longer to clear the reset bit than genuine parts. Increase this if
+    /// opening the device fails with `ConfigurationNotDefaultAfterReset`.
+    pub reset_retries: u8,
+}
+
+#[cfg(any(feature = "sync", feature = "async"))]
+impl InitOptions {
+    /// The options used by default
+    pub const DEFAULT: Self = Self { reset_retries: 11 };
+}
+
+#[cfg(any(feature = "sync", feature = "async"))]
+impl Default for InitOptions {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+#[cfg(all(feature = "sync", feature = "std"))]
+pub mod csv;
+
+#[cfg(feature = "std")]
+pub mod energy;
 
+#[cfg(all(test, feature = "async"))]
+mod test_util;
 #[cfg(all(test, feature = "sync"))]
 mod tests;
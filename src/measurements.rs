@@ -3,8 +3,10 @@
 //! Types wrapping the measurements of the INA219
 //!
 //! These types help converting the ras register values into expressive values.
+use crate::calibration::impl_unit_arithmetic;
 use crate::configuration::{BusVoltageRange, ShuntVoltageRange};
-use core::fmt::{Debug, Display, Formatter};
+use core::fmt::{Debug, Display, Formatter, Write};
+use core::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
 
 #[cfg(doc)]
 use crate::configuration::OperatingMode::{AdcOff, PowerDown};
@@ -12,6 +14,8 @@ use crate::register::{ReadRegister, Register};
 
 /// A collection of all the measurements collected by the INA219
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct Measurements<Current, Power> {
     /// Measured `BusVoltage`
     pub bus_voltage: BusVoltage,
@@ -23,8 +27,163 @@ pub struct Measurements<Current, Power> {
     pub power: Power,
 }
 
+impl<Current, Power> Measurements<Current, Power> {
+    /// Compare only the bus and shunt voltage of two measurements
+    ///
+    /// This allows comparing measurements with different calibrations.
+    ///
+    /// # Example
+    /// ```rust
+    /// use ina219::calibration::{MicroAmpere, MicroWatt};
+    /// use ina219::measurements::{BusVoltage, Measurements, ShuntVoltage};
+    ///
+    /// let uncalibrated = Measurements {
+    ///     bus_voltage: BusVoltage::from_mv(12_000),
+    ///     shunt_voltage: ShuntVoltage::from_10uv(100),
+    ///     current: (),
+    ///     power: (),
+    /// };
+    /// let calibrated = Measurements {
+    ///     bus_voltage: BusVoltage::from_mv(12_000),
+    ///     shunt_voltage: ShuntVoltage::from_10uv(100),
+    ///     current: MicroAmpere(1_000),
+    ///     power: MicroWatt(12_000),
+    /// };
+    /// assert!(calibrated.voltages_eq(&uncalibrated));
+    /// ```
+    pub fn voltages_eq<C2, P2>(&self, other: &Measurements<C2, P2>) -> bool {
+        self.bus_voltage == other.bus_voltage && self.shunt_voltage == other.shunt_voltage
+    }
+}
+
+/// A subset of the measurements, registers that were not read are `None`
+///
+/// Returned by `INA219::read_selected`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SelectedMeasurements<Current, Power> {
+    /// Measured `BusVoltage`
+    pub bus_voltage: Option<BusVoltage>,
+    /// Measured `ShuntVoltage`
+    pub shunt_voltage: Option<ShuntVoltage>,
+    /// Measured `Current`
+    pub current: Option<Current>,
+    /// Measured `Power`
+    pub power: Option<Power>,
+}
+
+/// A value together with the time it was taken
+///
+/// Returned by `INA219::next_measurement_at`. The type of the timestamp is up to the caller, e.g.
+/// `std::time::Instant` or the tick count of an embedded timer.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Timestamped<T, M> {
+    /// Time the value was taken
+    pub timestamp: T,
+    /// The value itself
+    pub value: M,
+}
+
+/// Prints all measurements on one line separated by `|`
+///
+/// Every value is printed with its own `Display` and right-aligned in a field of fixed width, so the
+/// separators of consecutive lines line up, e.g. `16000 mV |      80 mV |    79600 µA | 1272000 µW`.
+impl<Current: Display, Power: Display> Display for Measurements<Current, Power> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write_aligned(f, self.bus_voltage, 8)?;
+        f.write_str(" | ")?;
+        write_aligned(f, self.shunt_voltage, 10)?;
+        f.write_str(" | ")?;
+        write_aligned(f, &self.current, 11)?;
+        f.write_str(" | ")?;
+        write_aligned(f, &self.power, 10)
+    }
+}
+
+/// Write `value` right-aligned in a field of at least `width` characters
+fn write_aligned(f: &mut Formatter<'_>, value: impl Display, width: usize) -> core::fmt::Result {
+    /// Counts the characters written to it
+    struct Counter(usize);
+
+    impl Write for Counter {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            self.0 += s.chars().count();
+            Ok(())
+        }
+    }
+
+    let mut counter = Counter(0);
+    write!(counter, "{value}")?;
+    for _ in counter.0..width {
+        f.write_char(' ')?;
+    }
+    write!(f, "{value}")
+}
+
+/// Reinterpret the bits of a register as a two's complement signed value
+///
+/// The shunt voltage and current registers hold signed values. This turns the raw `u16`, e.g.
+/// from [`CurrentRegister`], into the signed value it represents.
+///
+/// # Example
+/// ```rust
+/// use ina219::measurements::sign_extend;
+///
+/// assert_eq!(sign_extend(0x0001), 1);
+/// assert_eq!(sign_extend(0xFFFF), -1);
+/// assert_eq!(sign_extend(0x8000), i16::MIN);
+/// ```
+#[must_use]
+#[allow(clippy::cast_possible_wrap)] // The wrap is the two's complement we want
+pub const fn sign_extend(bits: u16) -> i16 {
+    bits as i16
+}
+
+/// A voltage in µV
+///
+/// Returned by the typed voltage accessors like [`ShuntVoltage::micro_volts`], so values in
+/// different units can not be mixed up by accident. It is wider than [`MilliVolt`], so every
+/// [`MilliVolt`] can be converted without overflow.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct MicroVolt(pub i64);
+
+impl_unit_arithmetic!(MicroVolt, i64);
+
+impl Display for MicroVolt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} µV", self.0)
+    }
+}
+
+impl From<MilliVolt> for MicroVolt {
+    fn from(value: MilliVolt) -> Self {
+        Self(i64::from(value.0) * 1000)
+    }
+}
+
+/// A voltage in mV
+///
+/// Returned by the typed voltage accessors like [`BusVoltage::milli_volts`], so values in
+/// different units can not be mixed up by accident.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct MilliVolt(pub i32);
+
+impl_unit_arithmetic!(MilliVolt, i32);
+
+impl Display for MilliVolt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} mV", self.0)
+    }
+}
+
 /// Errors that can arise when current and power are calculated
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum MathErrors {
     /// The INA219 reported a math overflow during the calculation
     MathOverflow,
@@ -45,6 +204,7 @@ impl ShuntVoltage {
     /// Turns the bits of the register into a `ShuntVoltage` checking that it is in the range given
     /// by `range`.
     #[must_use]
+    #[cfg_attr(feature = "minimal", allow(dead_code))]
     pub(crate) const fn from_bits_with_range(
         reg: ShuntVoltageRegister,
         range: ShuntVoltageRange,
@@ -62,7 +222,32 @@ impl ShuntVoltage {
     /// Turns the bits of the register into a `ShuntVoltage` without performing any range checks.
     #[must_use]
     pub(crate) const fn from_bits_unchecked(reg: ShuntVoltageRegister) -> Self {
-        Self(i16::from_ne_bytes(reg.0.to_ne_bytes()))
+        Self(sign_extend(reg.0))
+    }
+
+    /// Limit the shunt voltage to the full scale of `range`
+    ///
+    /// # Example
+    /// ```
+    /// use ina219::configuration::ShuntVoltageRange;
+    /// use ina219::measurements::ShuntVoltage;
+    ///
+    /// let spike = ShuntVoltage::from_10uv(-5_000); // -50mV
+    /// let clamped = spike.clamp_to_range(ShuntVoltageRange::Fsr40mv);
+    /// assert_eq!(clamped.shunt_voltage_mv(), -40);
+    /// ```
+    #[must_use]
+    pub const fn clamp_to_range(self, range: ShuntVoltageRange) -> Self {
+        let range = range.range_mv();
+        let min = *range.start() * 100;
+        let max = *range.end() * 100;
+        if self.0 < min {
+            Self(min)
+        } else if self.0 > max {
+            Self(max)
+        } else {
+            self
+        }
     }
 
     /// Get the shunt voltage in 10µV, this is the resolution reported by the INA219.
@@ -81,12 +266,68 @@ impl ShuntVoltage {
         i32::from(self.0) * 10
     }
 
+    /// Get the shunt voltage as [`MicroVolt`]
+    ///
+    /// Prefer this over the raw accessors like [`Self::shunt_voltage_uv`] unless you need plain
+    /// integers, the unit is part of the type.
+    #[must_use]
+    #[allow(clippy::cast_lossless)] // i64::from is not const
+    pub const fn micro_volts(self) -> MicroVolt {
+        MicroVolt(self.0 as i64 * 10)
+    }
+
+    /// Get the shunt voltage as [`MilliVolt`], truncating trailing digits
+    #[must_use]
+    #[allow(clippy::cast_lossless)] // i32::from is not const
+    pub const fn milli_volts(self) -> MilliVolt {
+        MilliVolt(self.shunt_voltage_mv() as i32)
+    }
+
     /// Get the shunt voltage in mV, truncating trailing digits
     #[must_use]
     pub const fn shunt_voltage_mv(self) -> i16 {
         self.0 / 100
     }
 
+    /// Compute the current through a shunt of `r_shunt_uohm` µOhm in µA, rounded to the nearest µA
+    ///
+    /// This applies Ohm's law directly to the measured shunt voltage, bypassing the calibration
+    /// register and the rounding errors of the INA219's integer math. It pairs well with a driver
+    /// that is [`UnCalibrated`](crate::calibration::UnCalibrated). Returns `None` if
+    /// `r_shunt_uohm` is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use ina219::measurements::ShuntVoltage;
+    ///
+    /// // 32mV over a 100mOhm shunt
+    /// assert_eq!(ShuntVoltage::from_10uv(3_200).current_ua(100_000), Some(320_000));
+    /// ```
+    #[must_use]
+    pub fn current_ua(self, r_shunt_uohm: u32) -> Option<i64> {
+        if r_shunt_uohm == 0 {
+            return None;
+        }
+
+        let r = u64::from(r_shunt_uohm);
+        let magnitude = (u64::from(self.shunt_voltage_uv().unsigned_abs()) * 1_000_000 + r / 2) / r;
+        // The magnitude is at most 327_680_000_000, so it always fits
+        #[allow(clippy::cast_possible_wrap)]
+        let magnitude = magnitude as i64;
+        if self.0 < 0 {
+            Some(-magnitude)
+        } else {
+            Some(magnitude)
+        }
+    }
+
+    /// Get the shunt voltage in V
+    #[cfg(feature = "float")]
+    #[must_use]
+    pub fn shunt_voltage_v(self) -> f32 {
+        f32::from(self.0) / 100_000.0
+    }
+
     /// For testing: create a `ShuntVoltage` from a value of unit 10µV
     ///
     /// # Example
@@ -99,14 +340,46 @@ impl ShuntVoltage {
         Self(uv)
     }
 
+    /// Get the shunt voltage in mV as a fixed-point number
+    ///
+    /// The value is exact up to the resolution of `I16F16`, trailing digits are truncated.
+    #[cfg(feature = "fixed")]
+    #[must_use]
+    pub fn shunt_voltage_mv_fixed(self) -> fixed::types::I16F16 {
+        fixed::types::I16F16::from_num(self.0) / 100
+    }
+
+    /// The shunt voltage as signed fraction of the full scale of `range`
+    ///
+    /// The result is within `-1.0..=1.0` if the voltage fits into the range.
+    ///
+    /// # Example
+    /// ```rust
+    /// use ina219::configuration::ShuntVoltageRange;
+    /// use ina219::measurements::ShuntVoltage;
+    ///
+    /// let shunt = ShuntVoltage::from_10uv(-2_000); // -20mV
+    /// assert_eq!(shunt.fraction_of_range(ShuntVoltageRange::Fsr40mv), -0.5);
+    /// ```
+    #[cfg(feature = "float")]
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)] // f32 is only used for display purposes
+    pub fn fraction_of_range(self, range: ShuntVoltageRange) -> f32 {
+        self.shunt_voltage_uv() as f32 / (f32::from(*range.range_mv().end()) * 1000.0)
+    }
+
+    #[allow(clippy::cast_sign_loss)] // Turns the value back into its two's complement bits
     pub(crate) const fn raw(self) -> u16 {
-        u16::from_ne_bytes(self.0.to_ne_bytes())
+        self.0 as u16
     }
 }
 
+/// Prints the voltage in µV or mV depending on its magnitude, e.g. `40 µV` or `320 mV`
+///
+/// Use [`ShuntVoltage::shunt_voltage_uv`] to get a value with a fixed unit.
 impl Display for ShuntVoltage {
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
-        write!(f, "{} µV", self.shunt_voltage_uv())
+        write_si(f, i64::from(self.shunt_voltage_uv()), "V")
     }
 }
 
@@ -118,6 +391,43 @@ impl Debug for ShuntVoltage {
     }
 }
 
+#[cfg(feature = "uom")]
+impl From<ShuntVoltage> for uom::si::f64::ElectricPotential {
+    fn from(value: ShuntVoltage) -> Self {
+        Self::new::<uom::si::electric_potential::microvolt>(f64::from(value.shunt_voltage_uv()))
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for ShuntVoltage {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(
+            f,
+            "ShuntVoltage {{ micro_volt: {=i32} }}",
+            self.shunt_voltage_uv()
+        );
+    }
+}
+
+/// Serialized as the shunt voltage in µV
+#[cfg(feature = "serde")]
+impl serde::Serialize for ShuntVoltage {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i32(self.shunt_voltage_uv())
+    }
+}
+
+/// Deserialized from the shunt voltage in µV, digits below the resolution of 10µV are truncated
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ShuntVoltage {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let uv = i32::deserialize(deserializer)?;
+        i16::try_from(uv / 10)
+            .map(Self)
+            .map_err(|_| serde::de::Error::custom("shunt voltage out of range"))
+    }
+}
+
 #[derive(Copy, Clone)]
 pub(crate) struct ShuntVoltageRegister(u16);
 
@@ -140,6 +450,7 @@ pub struct BusVoltage(u16);
 impl BusVoltage {
     /// Create `BusVoltage` from the contents of the register checking that it is `range`.
     #[must_use]
+    #[cfg_attr(feature = "minimal", allow(dead_code))]
     pub(crate) const fn from_bits_with_range(
         reg: BusVoltageRegister,
         range: BusVoltageRange,
@@ -173,6 +484,80 @@ impl BusVoltage {
         self.voltage_4mv() * 4
     }
 
+    /// Maximum bus voltage the IC can measure in mV
+    ///
+    /// [`BusVoltageRange::Fsr32v`] allows readings up to 32V, but the bus input of the IC is only
+    /// rated for 26V.
+    pub const IC_MAX_MV: u16 = 26_000;
+
+    /// Check if the bus voltage is at most [`Self::IC_MAX_MV`]
+    ///
+    /// With [`BusVoltageRange::Fsr32v`] readings between 26V and 32V pass the range check but are
+    /// beyond what the IC can measure. Such values indicate a wiring or measurement fault.
+    #[must_use]
+    pub const fn is_within_ic_max(self) -> bool {
+        self.voltage_mv() <= Self::IC_MAX_MV
+    }
+
+    /// Return the bus voltage in µV
+    ///
+    /// This is always a multiple of 4000 but matches the unit of [`ShuntVoltage::shunt_voltage_uv`].
+    #[must_use]
+    pub const fn voltage_uv(self) -> u32 {
+        self.voltage_mv() as u32 * 1000
+    }
+
+    /// Return the bus voltage as [`MilliVolt`]
+    ///
+    /// Prefer this over the raw accessors like [`Self::voltage_mv`] unless you need plain
+    /// integers, the unit is part of the type.
+    #[must_use]
+    #[allow(clippy::cast_lossless)] // i32::from is not const
+    pub const fn milli_volts(self) -> MilliVolt {
+        MilliVolt(self.voltage_mv() as i32)
+    }
+
+    /// Return the bus voltage as [`MicroVolt`]
+    #[must_use]
+    #[allow(clippy::cast_lossless)] // i64::from is not const
+    pub const fn micro_volts(self) -> MicroVolt {
+        MicroVolt(self.voltage_mv() as i64 * 1000)
+    }
+
+    /// Return the bus voltage in V
+    #[cfg(feature = "float")]
+    #[must_use]
+    pub fn voltage_v(self) -> f32 {
+        f32::from(self.voltage_mv()) / 1000.0
+    }
+
+    /// Return the bus voltage in V as a fixed-point number
+    ///
+    /// The value is exact up to the resolution of `I16F16`, trailing digits are truncated.
+    #[cfg(feature = "fixed")]
+    #[must_use]
+    pub fn voltage_volts_fixed(self) -> fixed::types::I16F16 {
+        fixed::types::I16F16::from_num(self.voltage_mv()) / 1000
+    }
+
+    /// The bus voltage as fraction of the full scale of `range`
+    ///
+    /// The result is within `0.0..=1.0` if the voltage fits into the range.
+    ///
+    /// # Example
+    /// ```rust
+    /// use ina219::configuration::BusVoltageRange;
+    /// use ina219::measurements::BusVoltage;
+    ///
+    /// let bus = BusVoltage::from_mv(4_000);
+    /// assert_eq!(bus.fraction_of_range(BusVoltageRange::Fsr16v), 0.25);
+    /// ```
+    #[cfg(feature = "float")]
+    #[must_use]
+    pub fn fraction_of_range(self, range: BusVoltageRange) -> f32 {
+        f32::from(self.voltage_mv()) / (f32::from(range.range_v().end) * 1000.0)
+    }
+
     /// Check if the conversion ready flag is set
     ///
     /// The registers of the INA219 always return the last measurement value. But this flag can be
@@ -182,6 +567,9 @@ impl BusVoltage {
     /// The flag is cleared if:
     /// * The operation mode of the configuration register is written (except for [`PowerDown`] or [`AdcOff`])
     /// * The power register was read
+    ///
+    /// When resuming from [`PowerDown`] the flag is undefined until the first new conversion
+    /// finished, so it should be cleared by reading the power register.
     #[must_use]
     pub const fn is_conversion_ready(self) -> bool {
         self.0 & 0b10 != 0
@@ -219,6 +607,46 @@ impl Debug for BusVoltage {
     }
 }
 
+#[cfg(feature = "uom")]
+impl From<BusVoltage> for uom::si::f64::ElectricPotential {
+    fn from(value: BusVoltage) -> Self {
+        Self::new::<uom::si::electric_potential::millivolt>(f64::from(value.voltage_mv()))
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for BusVoltage {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(
+            f,
+            "BusVoltage {{ milli_volt: {=u16}, has_math_overflowed: {=bool}, is_conversion_ready: {=bool} }}",
+            self.voltage_mv(),
+            self.has_math_overflowed(),
+            self.is_conversion_ready(),
+        );
+    }
+}
+
+/// Serialized as the bus voltage in mV, the conversion ready and math overflow flags are dropped
+#[cfg(feature = "serde")]
+impl serde::Serialize for BusVoltage {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u16(self.voltage_mv())
+    }
+}
+
+/// Deserialized from the bus voltage in mV, digits below the resolution of 4mV are truncated
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for BusVoltage {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mv = u16::deserialize(deserializer)?;
+        if mv / 4 > u16::MAX >> 3 {
+            return Err(serde::de::Error::custom("bus voltage out of range"));
+        }
+        Ok(Self::from_mv(mv))
+    }
+}
+
 #[derive(Copy, Clone)]
 pub(crate) struct BusVoltageRegister(u16);
 
@@ -260,6 +688,31 @@ impl ReadRegister for PowerRegister {
     }
 }
 
+/// Write a value given in µ`unit` with the largest SI prefix (µ, m or none) that keeps the integer
+/// part non-zero, trailing zeros of the fraction are omitted
+fn write_si(f: &mut Formatter<'_>, micro: i64, unit: &str) -> core::fmt::Result {
+    let abs = micro.unsigned_abs();
+    let (scale, digits, prefix) = match abs {
+        0..=999 => return write!(f, "{micro} µ{unit}"),
+        1_000..=999_999 => (1_000, 3, "m"),
+        _ => (1_000_000, 6, ""),
+    };
+
+    let sign = if micro < 0 { "-" } else { "" };
+    let int = abs / scale;
+    let mut frac = abs % scale;
+    if frac == 0 {
+        return write!(f, "{sign}{int} {prefix}{unit}");
+    }
+
+    let mut digits = digits;
+    while frac % 10 == 0 {
+        frac /= 10;
+        digits -= 1;
+    }
+    write!(f, "{sign}{int}.{frac:0digits$} {prefix}{unit}")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -338,6 +791,38 @@ mod tests {
         }
     }
 
+    #[test]
+    #[cfg(feature = "fixed")]
+    fn fixed_point() {
+        use fixed::types::I16F16;
+
+        let bv = BusVoltage::from_mv(16_000);
+        assert_eq!(bv.voltage_volts_fixed(), I16F16::from_num(16));
+
+        let bv = BusVoltage::from_mv(4);
+        assert_eq!(bv.voltage_volts_fixed(), I16F16::from_num(4) / 1000);
+        assert!(bv.voltage_volts_fixed() > I16F16::ZERO);
+
+        let sv = ShuntVoltage::from_10uv(-4000);
+        assert_eq!(sv.shunt_voltage_mv_fixed(), I16F16::from_num(-40));
+
+        let sv = ShuntVoltage::from_10uv(1);
+        assert_eq!(sv.shunt_voltage_mv_fixed(), I16F16::from_num(1) / 100);
+    }
+
+    #[test]
+    #[cfg(feature = "uom")]
+    fn uom_conversion() {
+        use uom::si::electric_potential::{millivolt, volt};
+        use uom::si::f64::ElectricPotential;
+
+        let bus = ElectricPotential::from(BusVoltage::from_mv(16_000));
+        assert!((bus.get::<volt>() - 16.0).abs() < 1e-9);
+
+        let shunt = ElectricPotential::from(ShuntVoltage::from_10uv(-4001));
+        assert!((shunt.get::<millivolt>() + 40.01).abs() < 1e-9);
+    }
+
     #[test]
     fn current() {
         let calib = IntCalibration::new(MicroAmpere(1), 1_000_000).unwrap();
@@ -352,4 +837,169 @@ mod tests {
         let c = calib.current_from_register(CurrentRegister(i16::MAX as u16));
         assert_eq!(c.0, i64::from(i16::MAX) * i64::from(u32::MAX));
     }
+
+    #[test]
+    fn shunt_voltage_display() {
+        for (value, expected) in [
+            (0, "0 µV"),
+            (4, "40 µV"),
+            (-99, "-990 µV"),
+            (100, "1 mV"),
+            (123, "1.23 mV"),
+            (-4001, "-40.01 mV"),
+            (3205, "32.05 mV"),
+            (32_000, "320 mV"),
+            (i16::MIN, "-327.68 mV"),
+        ] {
+            assert_eq!(ShuntVoltage::from_10uv(value).to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn measurements_display() {
+        use crate::calibration::{MicroAmpere, MicroWatt};
+
+        let m = Measurements {
+            bus_voltage: BusVoltage::from_mv(16_000),
+            shunt_voltage: ShuntVoltage::from_10uv(8_000),
+            current: MicroAmpere(79_600),
+            power: MicroWatt(1_272_000),
+        };
+        assert_eq!(
+            m.to_string(),
+            "16000 mV |      80 mV |    79600 µA | 1272000 µW"
+        );
+
+        let m = Measurements {
+            bus_voltage: BusVoltage::from_mv(3_304),
+            shunt_voltage: ShuntVoltage::from_10uv(-1),
+            current: MicroAmpere(-100),
+            power: MicroWatt(0),
+        };
+        assert_eq!(
+            m.to_string(),
+            " 3304 mV |     -10 µV |     -100 µA |       0 µW"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "float")]
+    fn fraction_of_range() {
+        let shunt = ShuntVoltage::from_10uv(32_000);
+        assert!((shunt.fraction_of_range(ShuntVoltageRange::Fsr320mv) - 1.0).abs() < f32::EPSILON);
+        let shunt = ShuntVoltage::from_10uv(-1_000);
+        assert!((shunt.fraction_of_range(ShuntVoltageRange::Fsr40mv) + 0.25).abs() < f32::EPSILON);
+
+        let bus = BusVoltage::from_mv(32_000);
+        assert!((bus.fraction_of_range(BusVoltageRange::Fsr32v) - 1.0).abs() < f32::EPSILON);
+        let bus = BusVoltage::from_mv(0);
+        assert!(bus.fraction_of_range(BusVoltageRange::Fsr16v).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn bus_voltage_uv() {
+        assert_eq!(BusVoltage::from_mv(0).voltage_uv(), 0);
+        assert_eq!(BusVoltage::from_mv(4).voltage_uv(), 4_000);
+        assert_eq!(BusVoltage::from_mv(32_000).voltage_uv(), 32_000_000);
+    }
+
+    #[test]
+    fn typed_voltages() {
+        let shunt = ShuntVoltage::from_10uv(-1_234);
+        assert_eq!(shunt.micro_volts(), MicroVolt(-12_340));
+        assert_eq!(shunt.milli_volts(), MilliVolt(-12));
+
+        let bus = BusVoltage::from_mv(16_000);
+        assert_eq!(bus.milli_volts(), MilliVolt(16_000));
+        assert_eq!(bus.micro_volts(), MicroVolt(16_000_000));
+
+        assert_eq!(MicroVolt(-12_340).to_string(), "-12340 µV");
+        assert_eq!(
+            MicroVolt::from(MilliVolt(i32::MIN)),
+            MicroVolt(-2_147_483_648_000)
+        );
+        assert_eq!(MilliVolt(16_000).to_string(), "16000 mV");
+
+        assert_eq!(
+            bus.micro_volts() + shunt.micro_volts(),
+            MicroVolt(15_987_660)
+        );
+        assert_eq!(-shunt.milli_volts() * 2, MilliVolt(24));
+        let mut sum = MilliVolt(0);
+        sum += bus.milli_volts();
+        sum -= MilliVolt(1_000);
+        assert_eq!(sum / 3, MilliVolt(5_000));
+    }
+
+    #[test]
+    fn within_ic_max() {
+        assert!(BusVoltage::from_mv(25_000).is_within_ic_max());
+        assert!(BusVoltage::from_mv(26_000).is_within_ic_max());
+        assert!(!BusVoltage::from_mv(30_000).is_within_ic_max());
+    }
+
+    #[test]
+    fn clamp_to_range() {
+        let range = ShuntVoltageRange::Fsr80mv;
+        for (ten_uv, clamped) in [
+            (9_000, 8_000),
+            (8_000, 8_000),
+            (-100, -100),
+            (i16::MIN, -8_000),
+        ] {
+            assert_eq!(
+                ShuntVoltage::from_10uv(ten_uv).clamp_to_range(range),
+                ShuntVoltage::from_10uv(clamped)
+            );
+        }
+    }
+
+    #[test]
+    fn current_from_shunt_voltage() {
+        assert_eq!(ShuntVoltage::from_10uv(100).current_ua(0), None);
+        assert_eq!(ShuntVoltage::from_10uv(0).current_ua(1), Some(0));
+        assert_eq!(ShuntVoltage::from_10uv(1).current_ua(3), Some(3_333_333));
+        assert_eq!(ShuntVoltage::from_10uv(-2).current_ua(3), Some(-6_666_667));
+        assert_eq!(
+            ShuntVoltage::from_10uv(i16::MIN).current_ua(1),
+            Some(-327_680_000_000)
+        );
+        assert_eq!(
+            ShuntVoltage::from_10uv(32_000).current_ua(100_000),
+            Some(3_200_000)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "float")]
+    fn volts() {
+        assert!((BusVoltage::from_mv(12_004).voltage_v() - 12.004).abs() < f32::EPSILON);
+        assert!(
+            (ShuntVoltage::from_10uv(-1_234).shunt_voltage_v() + 0.012_34).abs() < f32::EPSILON
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_round_trip() {
+        let m = Measurements {
+            bus_voltage: BusVoltage::from_mv(12_000),
+            shunt_voltage: ShuntVoltage::from_10uv(-4001),
+            current: MicroAmpere(-1_234),
+            power: crate::calibration::MicroWatt(5_678),
+        };
+
+        let json = serde_json::to_string(&m).unwrap();
+        assert_eq!(
+            json,
+            r#"{"bus_voltage":12000,"shunt_voltage":-40010,"current":-1234,"power":5678}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<Measurements<_, _>>(&json).unwrap(),
+            m
+        );
+
+        assert!(serde_json::from_str::<ShuntVoltage>("400000").is_err());
+        assert!(serde_json::from_str::<BusVoltage>("40000").is_err());
+    }
 }
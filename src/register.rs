@@ -1,23 +1,24 @@
 #![allow(clippy::module_name_repetitions)]
+#![cfg_attr(not(any(feature = "sync", feature = "async")), allow(dead_code))]
 
 /// Addresses of the internal registers of the INA219
 ///
-/// See [`INA219::read_raw()`]
+/// Used to select registers for raw access like `INA219::read_register` or `INA219::read_into`.
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[repr(u8)]
-#[cfg_attr(not(test), allow(dead_code))]
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum RegisterName {
-    /// Configuration register, see [`Configuration`]
+    /// Configuration register, see [`Configuration`](crate::configuration::Configuration)
     Configuration = 0x00,
-    /// Shunt voltage register, see [`ShuntVoltage`]
+    /// Shunt voltage register, see [`ShuntVoltage`](crate::measurements::ShuntVoltage)
     ShuntVoltage = 0x01,
-    /// Bus voltage register, see [`BusVoltage`]
+    /// Bus voltage register, see [`BusVoltage`](crate::measurements::BusVoltage)
     BusVoltage = 0x02,
-    /// Power register, see [`Power`]
+    /// Power register, see [`Calibration::Power`](crate::calibration::Calibration::Power)
     Power = 0x03,
-    /// Current register, see [`Current`]
+    /// Current register, see [`Calibration::Current`](crate::calibration::Calibration::Current)
     Current = 0x04,
-    /// Calibration register, see [`Calibration`]
+    /// Calibration register, see [`Calibration`](crate::calibration::Calibration)
     Calibration = 0x05,
 }
 
@@ -0,0 +1,162 @@
+//! Continuous measurements of the async driver as a [`Stream`]
+//!
+//! This is the async equivalent of the measurement iterator of the blocking driver. The stream
+//! plugs into the combinators of `StreamExt` from the `futures` crate.
+//!
+//! Use [`INA219::measurement_stream`] to create the stream.
+
+use crate::calibration::Calibration;
+use crate::errors::MeasurementError;
+use crate::measurements::Measurements;
+use crate::r#async::INA219;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use embedded_hal_async::delay::DelayNs;
+use embedded_hal_async::i2c::{ErrorType, I2c};
+use futures_core::Stream;
+
+/// Item of the measurement stream, the result of a single [`INA219::measure`]
+type Item<I2C, Calib> = Result<
+    Measurements<<Calib as Calibration>::Current, <Calib as Calibration>::Power>,
+    MeasurementError<<I2C as ErrorType>::Error>,
+>;
+
+impl<I2C, Calib> INA219<I2C, Calib>
+where
+    I2C: I2c,
+    Calib: Calibration,
+{
+    /// Continuously measure using `delay` to wait for each conversion
+    ///
+    /// Every item is the result of [`Self::measure`], so each poll awaits the conversion time of
+    /// the configuration and yields the completed conversion. If no measurement arrives within
+    /// twice the conversion time [`MeasurementError::ConversionTimeout`] is yielded once and the
+    /// stream ends. The same happens after [`MeasurementError::DeviceNotConverting`], since no
+    /// measurement will arrive after that. Otherwise the stream never ends on its own.
+    ///
+    /// The stream needs to be pinned before it is polled, e.g. with [`core::pin::pin`].
+    pub fn measurement_stream<'a, D: DelayNs>(
+        &'a mut self,
+        delay: &'a mut D,
+    ) -> impl Stream<Item = Item<I2C, Calib>> + 'a {
+        let next = step::<I2C, Calib, D>;
+        MeasurementStream {
+            next,
+            step: Some(next(self, delay)),
+        }
+    }
+}
+
+/// Perform one measurement and hand back the borrows for the next one
+async fn step<'a, I2C, Calib, D>(
+    ina: &'a mut INA219<I2C, Calib>,
+    delay: &'a mut D,
+) -> (Item<I2C, Calib>, &'a mut INA219<I2C, Calib>, &'a mut D)
+where
+    I2C: I2c,
+    Calib: Calibration,
+    D: DelayNs,
+{
+    let result = ina.measure(delay).await;
+    (result, ina, delay)
+}
+
+pin_project_lite::pin_project! {
+    /// Stream that runs the measurement future `F` created by `next` over and over
+    struct MeasurementStream<'a, I2C, Calib, D, F> {
+        next: fn(&'a mut INA219<I2C, Calib>, &'a mut D) -> F,
+        // Running measurement, `None` once the stream ended
+        #[pin]
+        step: Option<F>,
+    }
+}
+
+impl<'a, I2C, Calib, D, F> Stream for MeasurementStream<'a, I2C, Calib, D, F>
+where
+    I2C: I2c,
+    Calib: Calibration,
+    F: Future<Output = (Item<I2C, Calib>, &'a mut INA219<I2C, Calib>, &'a mut D)>,
+{
+    type Item = Item<I2C, Calib>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        let Some(fut) = this.step.as_mut().as_pin_mut() else {
+            return Poll::Ready(None);
+        };
+        let Poll::Ready((result, ina, delay)) = fut.poll(cx) else {
+            return Poll::Pending;
+        };
+
+        // Once the device stopped converting or timed out, waiting for more measurements is futile
+        if matches!(
+            result,
+            Err(MeasurementError::DeviceNotConverting | MeasurementError::ConversionTimeout)
+        ) {
+            this.step.set(None);
+        } else {
+            this.step.set(Some((this.next)(ina, delay)));
+        }
+        Poll::Ready(Some(result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::address::Address;
+    use crate::calibration::UnCalibrated;
+    use crate::configuration::{Configuration, OperatingMode};
+    use crate::test_util::{block_on, FakeI2c, PollDelay};
+    use core::pin::pin;
+
+    fn next<S: Stream>(stream: &mut Pin<&mut S>) -> Option<S::Item> {
+        block_on(core::future::poll_fn(|cx| stream.as_mut().poll_next(cx)))
+    }
+
+    #[test]
+    fn yields_measurements() {
+        let power_down = Configuration {
+            operating_mode: OperatingMode::PowerDown,
+            ..Configuration::default()
+        };
+        // The configuration is read before each measurement since `new_unchecked` does not know it
+        let i2c = FakeI2c {
+            configurations: vec![Configuration::default().as_bits(), power_down.as_bits()],
+            ready: true,
+            ..FakeI2c::default()
+        };
+
+        let mut ina = INA219::new_unchecked(i2c, Address::default(), UnCalibrated);
+        let mut delay = PollDelay { polls: 0 };
+        let mut stream = pin!(ina.measurement_stream(&mut delay));
+
+        let m = next(&mut stream).unwrap().expect("Measurement arrives");
+        assert_eq!(m.bus_voltage.voltage_mv(), 16_000);
+        assert!(matches!(
+            next(&mut stream),
+            Some(Err(MeasurementError::DeviceNotConverting))
+        ));
+        assert!(next(&mut stream).is_none());
+    }
+
+    #[test]
+    fn ends_after_timeout() {
+        let i2c = FakeI2c {
+            configurations: vec![Configuration::default().as_bits()],
+            ..FakeI2c::default()
+        };
+
+        let mut ina = INA219::new_unchecked(i2c, Address::default(), UnCalibrated);
+        let mut delay = PollDelay { polls: 0 };
+        let mut stream = pin!(ina.measurement_stream(&mut delay));
+
+        assert!(matches!(
+            next(&mut stream),
+            Some(Err(MeasurementError::ConversionTimeout))
+        ));
+        assert!(next(&mut stream).is_none());
+    }
+}
@@ -0,0 +1,93 @@
+//! Helpers for the tests of the async-only modules
+//!
+//! `embedded-hal-mock` has no async I2C mock, so these tests drive futures by hand on a fake bus.
+
+use crate::configuration::Configuration;
+use crate::register::{Register, RegisterName};
+use core::convert::Infallible;
+use core::future::{pending, poll_fn, Future};
+use core::pin::pin;
+use core::task::{Context, Poll, Waker};
+use embedded_hal_async::delay::DelayNs;
+use embedded_hal_async::i2c::{ErrorKind, ErrorType, I2c, Operation, SevenBitAddress};
+
+/// Drive a future to completion, this only works for futures that do not need to be woken
+pub fn block_on<F: Future>(fut: F) -> F::Output {
+    let mut fut = pin!(fut);
+    let mut cx = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(out) = fut.as_mut().poll(&mut cx) {
+            return out;
+        }
+    }
+}
+
+/// Delay that expires after being polled `polls` times, 0 returns immediately
+pub struct PollDelay {
+    pub polls: u32,
+}
+
+impl DelayNs for PollDelay {
+    async fn delay_ns(&mut self, _ns: u32) {
+        let mut remaining = self.polls;
+        poll_fn(|_| {
+            if remaining == 0 {
+                Poll::Ready(())
+            } else {
+                remaining -= 1;
+                Poll::Pending
+            }
+        })
+        .await;
+    }
+}
+
+/// INA219 at 16V on a bus that answers immediately unless it hangs
+///
+/// The configuration register reports `configurations` one after another, the default once they
+/// are used up. All registers apart from the bus voltage read 0.
+#[derive(Default)]
+pub struct FakeI2c {
+    /// Never finish any transaction
+    pub hang: bool,
+    pub configurations: Vec<u16>,
+    /// Whether the conversion ready flag is set
+    pub ready: bool,
+}
+
+impl ErrorType for FakeI2c {
+    type Error = ErrorKind;
+}
+
+impl I2c for FakeI2c {
+    async fn transaction(
+        &mut self,
+        _address: SevenBitAddress,
+        operations: &mut [Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        if self.hang {
+            pending::<Infallible>().await;
+        }
+
+        let mut register = 0;
+        for op in operations {
+            match op {
+                Operation::Write(bytes) => register = bytes[0],
+                Operation::Read(buf) => {
+                    let value = match register {
+                        Configuration::ADDRESS if self.configurations.is_empty() => {
+                            Configuration::default().as_bits()
+                        }
+                        Configuration::ADDRESS => self.configurations.remove(0),
+                        r if r == RegisterName::BusVoltage as u8 => {
+                            0x7d00 | (u16::from(self.ready) << 1)
+                        }
+                        _ => 0,
+                    };
+                    buf.copy_from_slice(&value.to_be_bytes());
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -1,7 +1,11 @@
 use crate::address::Address;
-use crate::calibration::{IntCalibration, MicroAmpere, UnCalibrated};
-use crate::configuration::{BusVoltageRange, ShuntVoltageRange};
-use crate::errors::{BusVoltageReadError, MeasurementError, ShuntVoltageReadError};
+use crate::calibration::{IntCalibration, MicroAmpere, RawCalibration, UnCalibrated};
+#[cfg(not(feature = "minimal"))]
+use crate::configuration::BusVoltageRange;
+use crate::configuration::ShuntVoltageRange;
+use crate::errors::MeasurementError;
+#[cfg(not(feature = "minimal"))]
+use crate::errors::{BusVoltageReadError, ShuntVoltageReadError};
 use crate::measurements::Measurements;
 use crate::register::RegisterName;
 use crate::SyncIna219 as INA219;
@@ -96,6 +100,7 @@ fn mock_uncal(transactions: &[Transaction]) -> INA219<I2cMock, UnCalibrated> {
 }
 
 /// Create an calibrated `INA219` that will react with the given transactions to a test
+#[allow(clippy::decimal_bitwise_operands)] // 409 = 0.04096 / (100µA * 1Ohm) reads best in decimal
 fn mock_cal(transactions: &[Transaction]) -> INA219<I2cMock, IntCalibration> {
     let mut all_transactions = init_transactions();
     all_transactions.push(write_reg(RegisterName::Calibration, 409 & !1));
@@ -110,77 +115,1182 @@ fn mock_cal(transactions: &[Transaction]) -> INA219<I2cMock, IntCalibration> {
     .unwrap()
 }
 
+/// Create an `INA219` with a raw calibration that will react with the given transactions to a test
+fn mock_raw(bits: u16, transactions: &[Transaction]) -> INA219<I2cMock, RawCalibration> {
+    let mut all_transactions = init_transactions();
+    all_transactions.push(write_reg(RegisterName::Calibration, bits));
+    all_transactions.extend_from_slice(transactions);
+    let mock = I2cMock::new(&all_transactions);
+
+    INA219::new_calibrated(mock, Address::default(), RawCalibration(bits)).unwrap()
+}
+
 /// Calculate the value for the bus voltage register without the flags
 const fn bus_voltage(milli_volts: u16) -> u16 {
     (milli_volts / 4) << 3
 }
 
-const CONVERSION_READY: u16 = 0b10;
-const MATH_OVERFLOW: u16 = 0b01;
+const CONVERSION_READY: u16 = 0b10;
+const MATH_OVERFLOW: u16 = 0b01;
+
+#[test]
+fn initialization() {
+    let ina = mock_uncal(&[]);
+    ina.destroy().done();
+}
+
+#[test]
+fn initialization_cal() {
+    let ina = mock_cal(&[]);
+    ina.destroy().done();
+}
+
+#[test]
+fn initialization_slow_reset() {
+    use embedded_hal_mock::eh1::delay::NoopDelay;
+
+    let mut transactions = init_transactions();
+    // The device still reports the reset bit twice before it finished resetting
+    transactions.splice(
+        1..1,
+        [
+            read_reg(RegisterName::Configuration, 0b1011_1001_1001_1111),
+            read_reg(RegisterName::Configuration, 0b1011_1001_1001_1111),
+        ],
+    );
+    let mock = I2cMock::new(&transactions);
+
+    let ina = INA219::new_with_delay(mock, Address::default(), &mut NoopDelay).unwrap();
+    ina.destroy().done();
+}
+
+#[test]
+fn initialization_reset_retries() {
+    use crate::errors::InitializationErrorReason;
+    use crate::InitOptions;
+    use embedded_hal_mock::eh1::delay::NoopDelay;
+    use RegisterName::Configuration;
+
+    let options = InitOptions { reset_retries: 1 };
+    let mut transactions = vec![write_reg(Configuration, 0b1011_1001_1001_1111)];
+    // The device never finishes the reset, so it gives up after the first read and 1 retry
+    transactions.extend((0..2).map(|_| read_reg(Configuration, 0b1011_1001_1001_1111)));
+    let mock = I2cMock::new(&transactions);
+
+    let Err(mut err) = INA219::new_with_options(
+        mock,
+        Address::default(),
+        UnCalibrated,
+        &mut NoopDelay,
+        options,
+    ) else {
+        panic!("Initialization should fail");
+    };
+    assert!(matches!(
+        err.reason,
+        InitializationErrorReason::ConfigurationNotDefaultAfterReset
+    ));
+    err.device.done();
+}
+
+#[test]
+fn initialization_reset_default_retries() {
+    use crate::errors::InitializationErrorReason;
+    use RegisterName::Configuration;
+
+    let mut transactions = vec![write_reg(Configuration, 0b1011_1001_1001_1111)];
+    // The first read and 11 retries, like before the retries were configurable
+    transactions.extend((0..12).map(|_| read_reg(Configuration, 0b1011_1001_1001_1111)));
+    let mock = I2cMock::new(&transactions);
+
+    let Err(mut err) = INA219::new(mock, Address::default()) else {
+        panic!("Initialization should fail");
+    };
+    assert!(matches!(
+        err.reason,
+        InitializationErrorReason::ConfigurationNotDefaultAfterReset
+    ));
+    err.device.done();
+}
+
+#[test]
+fn initialization_no_reset() {
+    use RegisterName::{BusVoltage, Calibration, Configuration, ShuntVoltage};
+
+    let config = crate::configuration::Configuration {
+        bus_voltage_range: crate::configuration::BusVoltageRange::Fsr16v,
+        ..Default::default()
+    };
+    let mut transactions = vec![];
+    if cfg!(feature = "paranoid") {
+        transactions.extend([
+            read_reg(Configuration, config.as_bits()),
+            read_reg(ShuntVoltage, 0b0001_1111_0100_0000),
+            read_reg(BusVoltage, bus_voltage(12_000) | CONVERSION_READY),
+        ]);
+    }
+    // No reset is written, only the calibration
+    transactions.push(write_reg(Calibration, 408));
+    let mock = I2cMock::new(&transactions);
+
+    let ina = INA219::new_no_reset(
+        mock,
+        Address::default(),
+        IntCalibration::new(MicroAmpere(100), 1_000_000).unwrap(),
+    )
+    .unwrap();
+    let expected = cfg!(feature = "paranoid").then_some(config);
+    assert_eq!(ina.cached_configuration(), expected);
+
+    ina.destroy().done();
+}
+
+#[test]
+fn read_configuration_bits() {
+    use RegisterName::Configuration;
+
+    // The raw bits are returned even if they do not match the saved configuration
+    let mut ina = mock_uncal(&[read_reg(Configuration, 0b0011_1001_1001_1011)]);
+
+    assert_eq!(ina.configuration_bits().unwrap(), 0b0011_1001_1001_1011);
+
+    ina.destroy().done();
+}
+
+#[test]
+fn configuration_dont_care_bits() {
+    use crate::configuration::Resolution;
+    use RegisterName::Configuration;
+
+    // Bit 14 and the ADC3 bits of both resolutions are ignored by the device
+    const DONT_CARE: u16 = 0b0100_0010_0010_0000;
+
+    let conf = crate::configuration::Configuration {
+        bus_resolution: Resolution::Res9Bit,
+        shunt_resolution: Resolution::Res10Bit,
+        ..Default::default()
+    };
+    let mut ina = mock_uncal(&[
+        read_reg(Configuration, 0b0011_1001_1001_1111 | DONT_CARE),
+        write_reg(Configuration, conf.as_bits()),
+        read_reg(Configuration, conf.as_bits() | DONT_CARE),
+    ]);
+
+    // Neither the reset value nor a configuration that was written is reported as a mismatch
+    assert_eq!(
+        ina.configuration().unwrap(),
+        crate::configuration::Configuration::default()
+    );
+    ina.set_configuration(conf).unwrap();
+    assert_eq!(ina.configuration().unwrap(), conf);
+
+    ina.destroy().done();
+}
+
+#[test]
+fn cached_configuration() {
+    use RegisterName::Configuration;
+
+    let conf = crate::configuration::Configuration {
+        bus_voltage_range: crate::configuration::BusVoltageRange::Fsr16v,
+        ..Default::default()
+    };
+    let mut ina = mock_uncal(&[write_reg(Configuration, conf.as_bits())]);
+
+    let expected = |c| cfg!(feature = "paranoid").then_some(c);
+    assert_eq!(
+        ina.cached_configuration(),
+        expected(crate::configuration::Configuration::default())
+    );
+
+    ina.set_configuration(conf).unwrap();
+    assert_eq!(ina.cached_configuration(), expected(conf));
+
+    ina.destroy().done();
+}
+
+#[test]
+fn trust_configuration() {
+    use embedded_hal_mock::eh1::delay::NoopDelay;
+    use RegisterName::{BusVoltage, Power, ShuntVoltage};
+
+    let conf = crate::configuration::Configuration {
+        bus_voltage_range: crate::configuration::BusVoltageRange::Fsr16v,
+        ..Default::default()
+    };
+
+    // Only without paranoid the configuration is read to find the conversion time
+    let mut transactions = known_configuration();
+    transactions.extend(read_many(&[
+        (BusVoltage, bus_voltage(12_000) | CONVERSION_READY),
+        (Power, 0),
+        (ShuntVoltage, 0),
+    ]));
+    let mut ina = mock_uncal(&transactions);
+
+    ina.trust_configuration(conf);
+    let expected = cfg!(feature = "paranoid").then_some(conf);
+    assert_eq!(ina.cached_configuration(), expected);
+
+    ina.measure(&mut NoopDelay).expect("Measurement arrives");
+
+    ina.destroy().done();
+}
+
+#[test]
+fn set_configuration_reset() {
+    use crate::configuration::Reset;
+    use RegisterName::Configuration;
+
+    let reset = crate::configuration::Configuration {
+        reset: Reset::Reset,
+        ..Default::default()
+    };
+    let default = crate::configuration::Configuration::default().as_bits();
+    let mut ina = mock_uncal(&[
+        write_reg(Configuration, reset.as_bits()),
+        // The saved configuration is forgotten, so the trigger has to read it
+        read_reg(Configuration, default),
+        write_reg(Configuration, default),
+    ]);
+
+    ina.set_configuration(reset).unwrap();
+    assert_eq!(ina.cached_configuration(), None);
+    ina.trigger().unwrap();
+
+    ina.destroy().done();
+}
+
+#[test]
+fn reconfigure() {
+    use RegisterName::{Calibration, Configuration, Power};
+
+    let conf = crate::configuration::Configuration {
+        shunt_voltage_range: ShuntVoltageRange::Fsr40mv,
+        ..Default::default()
+    };
+    let calib = IntCalibration::new(MicroAmpere(10), 1_000_000).unwrap();
+    let mut ina = mock_cal(&[
+        write_reg(Configuration, conf.as_bits()),
+        write_reg(Calibration, calib.as_bits()),
+        read_reg(Power, 0),
+    ]);
+
+    ina.reconfigure(conf, calib).unwrap();
+    assert_eq!(
+        ina.cached_configuration(),
+        cfg!(feature = "paranoid").then_some(conf)
+    );
+
+    let (mut i2c, new_calib) = ina.release();
+    assert_eq!(new_calib, calib);
+    i2c.done();
+}
+
+#[test]
+fn set_operating_mode() {
+    use crate::configuration::OperatingMode;
+
+    let mut transactions = known_configuration();
+    transactions.push(write_reg(
+        RegisterName::Configuration,
+        0b0011_1001_1001_1000,
+    ));
+    let mut ina = mock_uncal(&transactions);
+
+    ina.set_operating_mode(OperatingMode::PowerDown).unwrap();
+
+    ina.destroy().done();
+}
+
+#[test]
+fn power_down_and_up() {
+    use RegisterName::Configuration;
+
+    let triggered = 0b0011_1001_1001_1011;
+    let mut transactions = known_configuration();
+    transactions.push(write_reg(Configuration, triggered));
+    if !cfg!(feature = "paranoid") {
+        transactions.push(read_reg(Configuration, triggered));
+    }
+    transactions.push(write_reg(Configuration, 0b0011_1001_1001_1000));
+    if !cfg!(feature = "paranoid") {
+        transactions.push(read_reg(Configuration, 0b0011_1001_1001_1000));
+    }
+    transactions.push(write_reg(Configuration, triggered));
+    // Clear the conversion ready flag
+    transactions.push(read_reg(RegisterName::Power, 0));
+    if !cfg!(feature = "paranoid") {
+        // Powering up again does nothing when not powered down
+        transactions.push(read_reg(Configuration, triggered));
+    }
+    let mut ina = mock_uncal(&transactions);
+
+    ina.set_operating_mode(crate::configuration::OperatingMode::Triggered(
+        crate::configuration::MeasuredSignals::ShutAndBusVoltage,
+    ))
+    .unwrap();
+    let mode = ina.power_down().unwrap();
+    assert_eq!(
+        mode,
+        crate::configuration::OperatingMode::Triggered(
+            crate::configuration::MeasuredSignals::ShutAndBusVoltage,
+        )
+    );
+    ina.power_up(mode).unwrap();
+    ina.power_up(mode).unwrap();
+
+    ina.destroy().done();
+}
+
+#[test]
+fn power_up_default_mode() {
+    use RegisterName::Configuration;
+
+    let powered_down = 0b0011_1001_1001_1000;
+    let mut transactions = vec![read_reg(Configuration, powered_down)];
+    if !cfg!(feature = "paranoid") {
+        transactions.push(read_reg(Configuration, powered_down));
+    }
+    transactions.push(write_reg(Configuration, 0b0011_1001_1001_1111));
+    transactions.push(read_reg(RegisterName::Power, 0));
+    let mut ina = mock_uncal(&transactions);
+
+    // Without the mode from a previous power down the default mode is used
+    ina.configuration().ok();
+    ina.power_up(crate::configuration::OperatingMode::default())
+        .unwrap();
+
+    ina.destroy().done();
+}
+
+#[test]
+fn provision() {
+    use crate::errors::ProvisionError;
+    use RegisterName::{Calibration, Configuration};
+
+    let config = crate::configuration::Configuration {
+        bus_voltage_range: crate::configuration::BusVoltageRange::Fsr16v,
+        ..Default::default()
+    };
+    let calib = IntCalibration::new(MicroAmpere(100), 1_000_000).unwrap();
+    let mut ina = mock_cal(&[
+        write_reg(Calibration, calib.as_bits()),
+        write_reg(Configuration, config.as_bits()),
+        read_reg(Configuration, config.as_bits()),
+        read_reg(Calibration, calib.as_bits()),
+        // The calibration does not take the second time
+        write_reg(Calibration, calib.as_bits()),
+        write_reg(Configuration, config.as_bits()),
+        read_reg(Configuration, config.as_bits()),
+        read_reg(Calibration, 0),
+    ]);
+
+    ina.provision(config, calib).unwrap();
+    assert!(matches!(
+        ina.provision(config, calib),
+        Err(ProvisionError::CalibrationMismatch { read: 0, written }) if written == calib.as_bits()
+    ));
+
+    ina.destroy().done();
+}
+
+#[test]
+fn calibrate_raw() {
+    let mut ina = mock_raw(
+        4096,
+        &[write_reg(RegisterName::Calibration, 0b0000_0001_1001_1000)],
+    );
+
+    ina.calibrate_raw(0b0000_0001_1001_1000).unwrap();
+
+    ina.destroy().done();
+}
+
+#[test]
+fn read_calibration() {
+    let mut ina = mock_cal(&[read_reg(RegisterName::Calibration, 408)]);
+
+    let bits = ina.read_calibration().unwrap();
+    assert_eq!(
+        IntCalibration::from_bits(bits, 1_000_000),
+        IntCalibration::new(MicroAmpere(100), 1_000_000)
+    );
+
+    ina.destroy().done();
+}
+
+#[test]
+fn calibrate_returns_bits() {
+    let mut ina = mock_cal(&[write_reg(RegisterName::Calibration, 4096)]);
+
+    // 10µA and 1Ohm
+    let calib = IntCalibration::new(MicroAmpere(10), 1_000_000).unwrap();
+    assert_eq!(ina.calibrate(calib).unwrap(), 4096);
+
+    ina.destroy().done();
+}
+
+#[test]
+fn calibrate_checked() {
+    use crate::calibration::CalibrationWarning;
+    use crate::configuration::{Configuration, OperatingMode};
+
+    let power_down = Configuration {
+        operating_mode: OperatingMode::PowerDown,
+        ..Configuration::default()
+    };
+    let calib = IntCalibration::new(MicroAmpere(100), 1_000_000).unwrap();
+
+    let mut transactions = vec![write_reg(RegisterName::Configuration, power_down.as_bits())];
+    if !cfg!(feature = "paranoid") {
+        // Without a saved configuration the operating mode has to be read
+        transactions.push(read_reg(RegisterName::Configuration, power_down.as_bits()));
+    }
+    transactions.push(write_reg(RegisterName::Calibration, calib.as_bits()));
+    let mut ina = mock_cal(&transactions);
+
+    ina.set_configuration(power_down).unwrap();
+    assert_eq!(
+        ina.calibrate_checked(calib).unwrap(),
+        Some(CalibrationWarning::DeviceNotConverting(
+            OperatingMode::PowerDown
+        ))
+    );
+
+    ina.destroy().done();
+}
+
+#[test]
+fn fingerprint() {
+    use RegisterName::{Calibration, Configuration};
+
+    let mut ina = mock_uncal(&[
+        read_reg(Configuration, 0b0011_1001_1001_1111),
+        read_reg(Calibration, 0),
+        read_reg(Configuration, 0b0011_1001_1001_1111),
+        read_reg(Calibration, 0),
+        read_reg(Configuration, 0b0011_1001_1001_1111),
+        read_reg(Calibration, 408),
+    ]);
+
+    let first = ina.fingerprint().unwrap();
+    assert_eq!(ina.fingerprint().unwrap(), first);
+    assert_ne!(ina.fingerprint().unwrap(), first);
+
+    ina.destroy().done();
+}
+
+#[test]
+fn set_shunt_range_checked() {
+    use crate::configuration::Configuration;
+    use crate::errors::RangeCalibrationError;
+
+    // Can represent up to ~65mA over a 1Ohm shunt
+    let calib = IntCalibration::new(MicroAmpere(2), 1_000_000).unwrap();
+    let narrowed = Configuration {
+        shunt_voltage_range: ShuntVoltageRange::Fsr80mv,
+        ..Configuration::default()
+    };
+
+    let mut transactions = init_transactions();
+    transactions.push(write_reg(RegisterName::Calibration, calib.as_bits()));
+    transactions.extend(known_configuration());
+    transactions.push(write_reg(RegisterName::Configuration, narrowed.as_bits()));
+    let mock = I2cMock::new(&transactions);
+    let mut ina = INA219::new_calibrated(mock, Address::default(), calib).unwrap();
+
+    ina.set_shunt_range_checked(ShuntVoltageRange::Fsr80mv)
+        .unwrap();
+
+    match ina.set_shunt_range_checked(ShuntVoltageRange::Fsr40mv) {
+        Err(RangeCalibrationError::RangeClipsCalibration {
+            range_max,
+            calibration_max,
+        }) => {
+            assert_eq!(range_max, MicroAmpere(40_000));
+            assert_eq!(calibration_max, MicroAmpere(65_534));
+        }
+        other => panic!("Unexpected result: {other:?}"),
+    }
+
+    ina.destroy().done();
+}
+
+#[test]
+fn set_shunt_range_checked_datasheet() {
+    use crate::configuration::Configuration;
+
+    // Example from the datasheet: 3.2A over 0.1Ohm with the LSB rounded up to 100µA
+    let calib = IntCalibration::new(MicroAmpere(100), 100_000).unwrap();
+    let config = Configuration {
+        shunt_voltage_range: ShuntVoltageRange::Fsr320mv,
+        ..Configuration::default()
+    };
+
+    let mut transactions = init_transactions();
+    transactions.push(write_reg(RegisterName::Calibration, calib.as_bits()));
+    transactions.extend(known_configuration());
+    transactions.push(write_reg(RegisterName::Configuration, config.as_bits()));
+    let mock = I2cMock::new(&transactions);
+    let mut ina = INA219::new_calibrated(mock, Address::default(), calib).unwrap();
+
+    ina.set_shunt_range_checked(ShuntVoltageRange::Fsr320mv)
+        .unwrap();
+
+    ina.destroy().done();
+}
+
+#[test]
+fn read_measurements() {
+    use RegisterName::{BusVoltage, Power, ShuntVoltage};
+
+    let mut ina = mock_uncal(&read_many(&[
+        // Should first read the bus voltage and see that the "Conversion Ready" flag is set
+        (BusVoltage, bus_voltage(16_000) | CONVERSION_READY),
+        // Should then read the power register to clear the "Conversion Ready" flag
+        (Power, 0),
+        // Since there is a new "Conversion Ready" the driver should read the shunt voltage
+        (ShuntVoltage, 0b0001_1111_0100_0000), // Borrowed from datasheet table
+    ]));
+
+    let m = ina
+        .next_measurement()
+        .expect("No errors occur")
+        .expect("There IS a new measurement");
+
+    assert_eq!(m.shunt_voltage.shunt_voltage_mv(), 80);
+    assert_eq!(m.bus_voltage.voltage_mv(), 16_000);
+
+    ina.destroy().done();
+}
+
+#[test]
+fn read_stale_measurements() {
+    use RegisterName::{BusVoltage, Power, ShuntVoltage};
+
+    let mut ina = mock_uncal(&read_many(&[
+        // The "Conversion Ready" flag is not set, but the values are still returned
+        (BusVoltage, bus_voltage(16_000)),
+        (Power, 0),
+        (ShuntVoltage, 0b0001_1111_0100_0000),
+    ]));
+
+    let (m, is_fresh) = ina.next_measurement_allow_stale().expect("No errors occur");
+
+    assert!(!is_fresh);
+    assert_eq!(m.shunt_voltage.shunt_voltage_mv(), 80);
+    assert_eq!(m.bus_voltage.voltage_mv(), 16_000);
+
+    ina.destroy().done();
+}
+
+#[test]
+fn read_measurements_with_cal() {
+    use RegisterName::{BusVoltage, Current, Power, ShuntVoltage};
+
+    let mut ina = mock_cal(&read_many(&[
+        (BusVoltage, bus_voltage(16_000) | CONVERSION_READY),
+        (Power, 636),
+        (ShuntVoltage, 0b0001_1111_0100_0000),
+        // Additionally to `read_measurements` test now should also read the current register
+        (Current, 796),
+    ]));
+
+    let m = ina
+        .next_measurement()
+        .expect("No errors")
+        .expect("There IS a new measurement");
+    assert_eq!(m.shunt_voltage.shunt_voltage_mv(), 80);
+    assert_eq!(m.bus_voltage.voltage_mv(), 16_000);
+
+    // These should be 80mA and 1280mW, but because of the slight error in the calibration they come
+    // out slightly different.
+    assert_eq!(m.current.0, 79_600);
+    assert_eq!(m.power.0, 1_272_000);
+
+    ina.destroy().done();
+}
+
+#[test]
+fn read_measurement_at() {
+    use RegisterName::{BusVoltage, Current, Power, ShuntVoltage};
+
+    let mut ina = mock_cal(&read_many(&[
+        (BusVoltage, bus_voltage(16_000) | CONVERSION_READY),
+        (Power, 636),
+        (ShuntVoltage, 0b0001_1111_0100_0000),
+        (Current, 796),
+    ]));
+
+    let m = ina
+        .next_measurement_at(|| 42_u32)
+        .expect("No errors")
+        .expect("There IS a new measurement");
+    assert_eq!(m.timestamp, 42);
+    assert_eq!(m.value.bus_voltage.voltage_mv(), 16_000);
+    assert_eq!(m.value.current.0, 79_600);
+
+    ina.destroy().done();
+}
+
+#[test]
+fn read_voltages() {
+    use RegisterName::{BusVoltage, ShuntVoltage};
+
+    let mut transactions = read_many(&[
+        (BusVoltage, bus_voltage(12_000)),
+        (ShuntVoltage, 0b0001_1111_0100_0000),
+    ]);
+    transactions.extend(read_many(&[(BusVoltage, 0), (ShuntVoltage, 32_001)]));
+    let mut ina = mock_uncal(&transactions);
+
+    let (bus, shunt) = ina.voltages().expect("No errors occur");
+    assert_eq!(bus.voltage_mv(), 12_000);
+    assert_eq!(shunt.shunt_voltage_mv(), 80);
+
+    // The shunt voltage is beyond the default range of 320mV
+    let result = ina.voltages();
+    if cfg!(feature = "minimal") {
+        assert!(result.is_ok());
+    } else {
+        assert!(matches!(
+            result,
+            Err(MeasurementError::ShuntVoltageReadError(_))
+        ));
+    }
+
+    ina.destroy().done();
+}
+
+#[test]
+fn read_voltages_full() {
+    use RegisterName::{BusVoltage, ShuntVoltage};
+
+    let mut ina = mock_uncal(&read_many(&[
+        (BusVoltage, bus_voltage(12_000)),
+        (ShuntVoltage, 0b0001_1111_0100_0000),
+    ]));
+
+    let (bus, shunt, supply_mv) = ina.read_voltages_full().expect("No errors occur");
+    assert_eq!(bus.voltage_mv(), 12_000);
+    assert_eq!(shunt.shunt_voltage_mv(), 80);
+    assert_eq!(supply_mv, 12_080);
+
+    ina.destroy().done();
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn csv_logger() {
+    use crate::csv::Ina219CsvLogger;
+    use RegisterName::{BusVoltage, Current, Power, ShuntVoltage};
+
+    let mut transactions = read_many(&[
+        (BusVoltage, bus_voltage(16_000) | CONVERSION_READY),
+        (Power, 636),
+        (ShuntVoltage, 0b0001_1111_0100_0000),
+        (Current, 796),
+    ]);
+    // The second read has no new data
+    transactions.extend(read_many(&[
+        (BusVoltage, bus_voltage(16_000)),
+        (Power, 636),
+        (ShuntVoltage, 0b0001_1111_0100_0000),
+        (Current, 796),
+    ]));
+
+    let mut logger = Ina219CsvLogger::new(mock_cal(&transactions), vec![]);
+    logger.write_header().unwrap();
+    assert!(logger.log_measurement().unwrap());
+    assert!(!logger.log_measurement().unwrap());
+
+    let (ina, out) = logger.destroy();
+    ina.destroy().done();
+
+    let out = String::from_utf8(out).unwrap();
+    let lines: Vec<_> = out.lines().collect();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0], crate::csv::HEADER);
+
+    let (_timestamp, values) = lines[1].split_once(',').unwrap();
+    assert_eq!(values, "16000,80000,79600,1272000");
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn csv_logger_io_error() {
+    use crate::csv::{CsvLogError, Ina219CsvLogger};
+    use RegisterName::{BusVoltage, Current, Power, ShuntVoltage};
+
+    /// Writer that rejects everything
+    struct FullWriter;
+
+    impl std::io::Write for FullWriter {
+        fn write(&mut self, _buf: &[u8]) -> std::io::Result<usize> {
+            Err(std::io::ErrorKind::WriteZero.into())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let transactions = read_many(&[
+        (BusVoltage, bus_voltage(16_000) | CONVERSION_READY),
+        (Power, 636),
+        (ShuntVoltage, 0b0001_1111_0100_0000),
+        (Current, 796),
+    ]);
+
+    let mut logger = Ina219CsvLogger::new(mock_cal(&transactions), FullWriter);
+    let err = logger.log_measurement().unwrap_err();
+    assert!(matches!(&err, CsvLogError::Io(io) if io.kind() == std::io::ErrorKind::WriteZero));
+    assert!(err.to_string().starts_with("IO error: "));
+
+    let (ina, _) = logger.destroy();
+    ina.destroy().done();
+}
+
+/// Transactions to look up the configuration, which is only needed if it was not saved
+fn known_configuration() -> Vec<Transaction> {
+    if cfg!(feature = "paranoid") {
+        vec![]
+    } else {
+        vec![read_reg(RegisterName::Configuration, 0b0011_1001_1001_1111)]
+    }
+}
 
 #[test]
-fn initialization() {
+fn await_conversion() {
+    use embedded_hal_mock::eh1::delay::NoopDelay;
+    use RegisterName::{BusVoltage, Power, ShuntVoltage};
+
+    let mut transactions = known_configuration();
+    transactions.extend([
+        read_reg(BusVoltage, bus_voltage(16_000)),
+        read_reg(BusVoltage, bus_voltage(16_000) | CONVERSION_READY),
+    ]);
+    transactions.extend(read_many(&[
+        (BusVoltage, bus_voltage(16_000) | CONVERSION_READY),
+        (Power, 0),
+        (ShuntVoltage, 0b0001_1111_0100_0000),
+    ]));
+    let mut ina = mock_uncal(&transactions);
+
+    let m = ina
+        .await_conversion(&mut NoopDelay, 100)
+        .expect("Measurement arrives");
+    assert_eq!(m.shunt_voltage.shunt_voltage_mv(), 80);
+
+    ina.destroy().done();
+}
+
+#[test]
+fn is_conversion_ready() {
+    use RegisterName::{BusVoltage, Power, ShuntVoltage};
+
+    let mut transactions = vec![
+        read_reg(BusVoltage, bus_voltage(16_000)),
+        read_reg(BusVoltage, bus_voltage(16_000) | CONVERSION_READY),
+        read_reg(BusVoltage, bus_voltage(16_000) | CONVERSION_READY),
+    ];
+    // The flag is still set, so the measurement is returned
+    transactions.extend(read_many(&[
+        (BusVoltage, bus_voltage(16_000) | CONVERSION_READY),
+        (Power, 0),
+        (ShuntVoltage, 0),
+    ]));
+    let mut ina = mock_uncal(&transactions);
+
+    assert!(!ina.is_conversion_ready().unwrap());
+    assert!(ina.is_conversion_ready().unwrap());
+    assert!(ina.is_conversion_ready().unwrap());
+    assert!(ina.next_measurement().unwrap().is_some());
+
+    ina.destroy().done();
+}
+
+#[test]
+fn next_measurement_peek() {
+    use RegisterName::{BusVoltage, Current, ShuntVoltage};
+
+    let mut transactions = read_many(&[
+        (BusVoltage, bus_voltage(16_000)),
+        (ShuntVoltage, 100),
+        (Current, 10),
+    ]);
+    // Without reading the power the flag stays set, so the same conversion is read twice
+    for _ in 0..2 {
+        transactions.extend(read_many(&[
+            (BusVoltage, bus_voltage(16_000) | CONVERSION_READY),
+            (ShuntVoltage, 100),
+            (Current, 10),
+        ]));
+    }
+    let mut ina = mock_cal(&transactions);
+
+    assert_eq!(ina.next_measurement_peek().unwrap(), None);
+    for _ in 0..2 {
+        let m = ina.next_measurement_peek().unwrap().unwrap();
+        assert_eq!(m.shunt_voltage.shunt_voltage_mv(), 1);
+        assert_eq!(m.current, MicroAmpere(1_000));
+    }
+
+    ina.destroy().done();
+}
+
+#[test]
+fn read_raw_measurements() {
+    use crate::measurements::{CurrentRegister, PowerRegister};
+    use RegisterName::{BusVoltage, Current, Power, ShuntVoltage};
+
+    // Out of range values and set flags are returned as is
+    let mut ina = mock_uncal(&read_many(&[
+        (
+            BusVoltage,
+            bus_voltage(32_004) | CONVERSION_READY | MATH_OVERFLOW,
+        ),
+        (Power, 1),
+        (ShuntVoltage, 32_001),
+        (Current, 0xFFFF),
+    ]));
+
+    let m = ina.read_raw_measurements().unwrap();
+    assert_eq!(m.bus_voltage.voltage_mv(), 32_004);
+    assert!(m.bus_voltage.is_conversion_ready());
+    assert!(m.bus_voltage.has_math_overflowed());
+    assert_eq!(m.shunt_voltage.shunt_voltage_10uv(), 32_001);
+    assert_eq!(m.current, CurrentRegister(0xFFFF));
+    assert_eq!(m.power, PowerRegister(1));
+
+    ina.destroy().done();
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn time_until_ready() {
+    use std::time::Duration;
+
     let ina = mock_uncal(&[]);
+
+    let expected = cfg!(feature = "paranoid").then_some(Duration::from_millis(1));
+    assert_eq!(ina.time_until_ready(Duration::from_micros(64)), expected);
+
     ina.destroy().done();
 }
 
 #[test]
-fn initialization_cal() {
-    let ina = mock_cal(&[]);
+fn await_conversion_timeout() {
+    use embedded_hal_mock::eh1::delay::NoopDelay;
+    use RegisterName::BusVoltage;
+
+    // The default configuration takes 1064µs, so after waiting 2128µs it should give up
+    let mut transactions = known_configuration();
+    transactions.extend([
+        read_reg(BusVoltage, bus_voltage(16_000)),
+        read_reg(BusVoltage, bus_voltage(16_000)),
+        read_reg(BusVoltage, bus_voltage(16_000)),
+        read_reg(BusVoltage, bus_voltage(16_000)),
+    ]);
+    let mut ina = mock_uncal(&transactions);
+
+    assert!(matches!(
+        ina.await_conversion(&mut NoopDelay, 1_000),
+        Err(MeasurementError::ConversionTimeout)
+    ));
+
     ina.destroy().done();
 }
 
 #[test]
-fn read_measurements() {
+fn measure_blocking() {
+    use embedded_hal_mock::eh1::delay::NoopDelay;
     use RegisterName::{BusVoltage, Power, ShuntVoltage};
 
-    let mut ina = mock_uncal(&read_many(&[
-        // Should first read the bus voltage and see that the "Conversion Ready" flag is set
+    let mut transactions = known_configuration();
+    transactions.extend(read_many(&[
         (BusVoltage, bus_voltage(16_000) | CONVERSION_READY),
-        // Should then read the power register to clear the "Conversion Ready" flag
         (Power, 0),
-        // Since there is a new "Conversion Ready" the driver should read the shunt voltage
-        (ShuntVoltage, 0b0001_1111_0100_0000), // Borrowed from datasheet table
+        (ShuntVoltage, 0b0001_1111_0100_0000),
     ]));
+    let mut ina = mock_uncal(&transactions);
 
     let m = ina
-        .next_measurement()
-        .expect("No errors occur")
-        .expect("There IS a new measurement");
-
-    assert_eq!(m.shunt_voltage.shunt_voltage_mv(), 80);
+        .measure_blocking(&mut NoopDelay)
+        .expect("Measurement arrives");
     assert_eq!(m.bus_voltage.voltage_mv(), 16_000);
 
     ina.destroy().done();
 }
 
 #[test]
-fn read_measurements_with_cal() {
-    use RegisterName::{BusVoltage, Current, Power, ShuntVoltage};
+fn measurements_iter() {
+    use crate::configuration::OperatingMode;
+    use embedded_hal_mock::eh1::delay::NoopDelay;
+    use RegisterName::{BusVoltage, Configuration, Power, ShuntVoltage};
 
-    let mut ina = mock_cal(&read_many(&[
-        (BusVoltage, bus_voltage(16_000) | CONVERSION_READY),
-        (Power, 636),
+    let mut transactions = Vec::new();
+    for mv in [16_000, 12_000] {
+        transactions.extend(known_configuration());
+        transactions.extend(read_many(&[
+            (BusVoltage, bus_voltage(mv) | CONVERSION_READY),
+            (Power, 0),
+            (ShuntVoltage, 0b0001_1111_0100_0000),
+        ]));
+    }
+    let power_down = crate::configuration::Configuration {
+        operating_mode: OperatingMode::PowerDown,
+        ..Default::default()
+    };
+    transactions.push(write_reg(Configuration, power_down.as_bits()));
+    if !cfg!(feature = "paranoid") {
+        transactions.push(read_reg(Configuration, power_down.as_bits()));
+    }
+    let mut ina = mock_uncal(&transactions);
+
+    let voltages: Vec<_> = ina
+        .measurements(&mut NoopDelay)
+        .take(2)
+        .map(|m| m.expect("Measurement arrives").bus_voltage.voltage_mv())
+        .collect();
+    assert_eq!(voltages, [16_000, 12_000]);
+
+    // The iterator ends once the device stops converting
+    ina.set_configuration(power_down).unwrap();
+    let mut delay = NoopDelay;
+    let mut iter = ina.measurements(&mut delay);
+    assert!(matches!(
+        iter.next(),
+        Some(Err(MeasurementError::DeviceNotConverting))
+    ));
+    assert!(iter.next().is_none());
+
+    ina.destroy().done();
+}
+
+#[test]
+fn measure_retries() {
+    use embedded_hal_mock::eh1::delay::NoopDelay;
+    use RegisterName::{BusVoltage, Power, ShuntVoltage};
+
+    let mut transactions = known_configuration();
+    // Not ready after the conversion time, so it is read again after a backoff
+    transactions.extend(read_many(&[
+        (BusVoltage, bus_voltage(16_000)),
+        (Power, 0),
+        (ShuntVoltage, 0),
+    ]));
+    transactions.extend(read_many(&[
+        (BusVoltage, bus_voltage(12_000) | CONVERSION_READY),
+        (Power, 0),
         (ShuntVoltage, 0b0001_1111_0100_0000),
-        // Additionally to `read_measurements` test now should also read the current register
-        (Current, 796),
     ]));
+    let mut ina = mock_uncal(&transactions);
+
+    let m = ina.measure(&mut NoopDelay).expect("Measurement arrives");
+    assert_eq!(m.bus_voltage.voltage_mv(), 12_000);
+
+    ina.destroy().done();
+}
+
+#[test]
+fn read_when() {
+    use RegisterName::{BusVoltage, Power, ShuntVoltage};
+
+    let mut transactions = read_many(&[
+        (BusVoltage, bus_voltage(16_000) | CONVERSION_READY),
+        (Power, 0),
+        (ShuntVoltage, 0),
+    ]);
+    // The second time the flag is not set
+    transactions.extend(read_many(&[
+        (BusVoltage, bus_voltage(16_000)),
+        (Power, 0),
+        (ShuntVoltage, 0),
+    ]));
+    let mut ina = mock_uncal(&transactions);
+
+    // Nothing is read while the source is not ready
+    assert!(ina.read_when(|| false).unwrap().is_none());
 
     let m = ina
-        .next_measurement()
-        .expect("No errors")
-        .expect("There IS a new measurement");
-    assert_eq!(m.shunt_voltage.shunt_voltage_mv(), 80);
+        .read_when(|| true)
+        .unwrap()
+        .expect("Measurement is ready");
     assert_eq!(m.bus_voltage.voltage_mv(), 16_000);
 
-    // These should be 80mA and 1280mW, but because of the slight error in the calibration they come
-    // out slightly different.
-    assert_eq!(m.current.0, 79_600);
-    assert_eq!(m.power.0, 1_272_000);
+    assert!(ina.read_when(|| true).unwrap().is_none());
+
+    ina.destroy().done();
+}
+
+#[test]
+fn next_measurement_not_converting() {
+    use crate::configuration::{Configuration, OperatingMode};
+    use RegisterName::{BusVoltage, Power, ShuntVoltage};
+
+    let adc_off = Configuration {
+        operating_mode: OperatingMode::AdcOff,
+        ..Configuration::default()
+    };
+    let mut transactions = vec![write_reg(RegisterName::Configuration, adc_off.as_bits())];
+    transactions.extend(read_many(&[
+        (BusVoltage, bus_voltage(16_000)),
+        (Power, 0),
+        (ShuntVoltage, 0),
+    ]));
+    let mut ina = mock_uncal(&transactions);
+
+    ina.set_configuration(adc_off).unwrap();
+    // Without paranoid the configuration is not known, so it is not read on every poll
+    let result = ina.next_measurement();
+    if cfg!(feature = "paranoid") {
+        assert!(matches!(result, Err(MeasurementError::DeviceNotConverting)));
+    } else {
+        assert!(matches!(result, Ok(None)));
+    }
+
+    ina.destroy().done();
+}
+
+#[test]
+fn read_current_and_bus() {
+    use RegisterName::{BusVoltage, Current};
+
+    let mut ina = mock_cal(&read_many(&[
+        (Current, 1234),
+        (BusVoltage, bus_voltage(12_000)),
+    ]));
+
+    let (current, bus) = ina.read_current_and_bus().unwrap();
+    assert_eq!(current, MicroAmpere(123_400));
+    assert_eq!(bus.voltage_mv(), 12_000);
+
+    ina.destroy().done();
+}
+
+#[test]
+fn raw_register_access() {
+    use RegisterName::{Calibration, Configuration};
+
+    let mut transactions = vec![
+        read_reg(Calibration, 0),
+        write_reg(Configuration, 0b0011_1001_1001_1011),
+    ];
+    // The saved configuration is discarded, so the next trigger has to read it
+    transactions.extend([
+        read_reg(Configuration, 0b0011_1001_1001_1011),
+        write_reg(Configuration, 0b0011_1001_1001_1011),
+    ]);
+    let mut ina = mock_uncal(&transactions);
+
+    assert_eq!(ina.read_register(Calibration).unwrap(), 0);
+    ina.write_register(Configuration, 0b0011_1001_1001_1011)
+        .unwrap();
+    ina.trigger().unwrap();
+
+    ina.destroy().done();
+}
+
+#[test]
+fn trigger_and_read() {
+    use crate::configuration::{Configuration, MeasuredSignals, OperatingMode};
+    use embedded_hal_mock::eh1::delay::NoopDelay;
+    use RegisterName::{BusVoltage, Power, ShuntVoltage};
+
+    let triggered = Configuration {
+        operating_mode: OperatingMode::Triggered(MeasuredSignals::ShutAndBusVoltage),
+        ..Configuration::default()
+    };
+    let lookup = if cfg!(feature = "paranoid") {
+        vec![]
+    } else {
+        vec![read_reg(RegisterName::Configuration, triggered.as_bits())]
+    };
+
+    let mut transactions = vec![write_reg(RegisterName::Configuration, triggered.as_bits())];
+    transactions.extend(lookup.clone());
+    transactions.push(write_reg(RegisterName::Configuration, triggered.as_bits()));
+    transactions.extend(lookup);
+    transactions.extend(read_many(&[
+        (BusVoltage, bus_voltage(12_000) | CONVERSION_READY),
+        (Power, 0),
+        (ShuntVoltage, 100),
+    ]));
+    let mut ina = mock_uncal(&transactions);
+
+    ina.set_configuration(triggered).unwrap();
+    let m = ina.trigger_and_read(&mut NoopDelay).unwrap();
+    assert_eq!(m.bus_voltage.voltage_mv(), 12_000);
+
+    ina.destroy().done();
+}
+
+#[test]
+fn read_selected() {
+    use crate::calibration::MicroWatt;
+    use crate::configuration::MeasuredSignals;
+    use RegisterName::{BusVoltage, Current, Power, ShuntVoltage};
+
+    let mut transactions = read_many(&[(BusVoltage, bus_voltage(12_000)), (Current, 10)]);
+    transactions.extend(read_many(&[(ShuntVoltage, 100), (Power, 3)]));
+    let mut ina = mock_cal(&transactions);
+
+    let m = ina
+        .read_selected(MeasuredSignals::BusVoltage, true, false)
+        .unwrap();
+    assert_eq!(
+        m.bus_voltage
+            .map(crate::measurements::BusVoltage::voltage_mv),
+        Some(12_000)
+    );
+    assert_eq!(m.shunt_voltage, None);
+    assert_eq!(m.current, Some(MicroAmpere(1_000)));
+    assert_eq!(m.power, None);
+
+    let m = ina
+        .read_selected(MeasuredSignals::ShuntVoltage, false, true)
+        .unwrap();
+    assert_eq!(m.bus_voltage, None);
+    assert_eq!(
+        m.shunt_voltage
+            .map(crate::measurements::ShuntVoltage::shunt_voltage_mv),
+        Some(1)
+    );
+    assert_eq!(m.current, None);
+    assert_eq!(m.power, Some(MicroWatt(6_000)));
+
+    ina.destroy().done();
+}
+
+#[test]
+fn read_into() {
+    use RegisterName::{BusVoltage, Current, ShuntVoltage};
+
+    let mut ina = mock_uncal(&read_many(&[
+        (BusVoltage, 0x1234),
+        (ShuntVoltage, 0xABCD),
+        (Current, 0x00FF),
+    ]));
+
+    let mut buf = [0; 6];
+    ina.read_into(&[BusVoltage, ShuntVoltage, Current], &mut buf)
+        .unwrap();
+    assert_eq!(buf, [0x12, 0x34, 0xAB, 0xCD, 0x00, 0xFF]);
 
     ina.destroy().done();
 }
 
+#[test]
+#[should_panic = "Buffer needs two bytes per register"]
+fn read_into_wrong_buffer() {
+    let mut ina = mock_uncal(&[]);
+
+    let mut buf = [0; 3];
+    let _ = ina.read_into(&[RegisterName::Power], &mut buf);
+}
+
 #[test]
 fn math_overflow() {
     use RegisterName::{BusVoltage, Power, ShuntVoltage};
@@ -214,6 +1324,7 @@ fn math_overflow() {
 }
 
 #[test]
+#[cfg(not(feature = "minimal"))]
 fn bus_out_of_range_values() {
     use RegisterName::BusVoltage;
 
@@ -234,6 +1345,7 @@ fn bus_out_of_range_values() {
 }
 
 #[test]
+#[cfg(not(feature = "minimal"))]
 fn shunt_out_of_range_values() {
     use RegisterName::ShuntVoltage;
 
@@ -252,3 +1364,185 @@ fn shunt_out_of_range_values() {
 
     ina.destroy().done();
 }
+
+#[test]
+#[cfg(not(feature = "minimal"))]
+fn shunt_voltage_clamped() {
+    use RegisterName::ShuntVoltage;
+
+    let mut ina = mock_cal(&[
+        read_reg(ShuntVoltage, 32_001),
+        read_reg(ShuntVoltage, 0x8000),
+        read_reg(ShuntVoltage, 100),
+    ]);
+
+    assert_eq!(
+        ina.shunt_voltage_clamped().unwrap().shunt_voltage_10uv(),
+        32_000
+    );
+    assert_eq!(
+        ina.shunt_voltage_clamped().unwrap().shunt_voltage_10uv(),
+        -32_000
+    );
+    assert_eq!(
+        ina.shunt_voltage_clamped().unwrap().shunt_voltage_10uv(),
+        100
+    );
+
+    ina.destroy().done();
+}
+
+#[test]
+#[cfg(feature = "minimal")]
+fn minimal_skips_range_checks() {
+    use RegisterName::{BusVoltage, ShuntVoltage};
+
+    let mut ina = mock_cal(&[
+        read_reg(BusVoltage, bus_voltage(32_004) | CONVERSION_READY),
+        read_reg(ShuntVoltage, 32_001),
+    ]);
+
+    // Out of range values are returned as reported by the device
+    assert_eq!(ina.bus_voltage().unwrap().voltage_mv(), 32_004);
+    assert_eq!(ina.shunt_voltage().unwrap().shunt_voltage_10uv(), 32_001);
+
+    ina.destroy().done();
+}
+
+#[test]
+fn scan_reports_acknowledging_addresses() {
+    use embedded_hal::i2c::{ErrorKind, NoAcknowledgeSource};
+
+    let expectations: Vec<_> = Address::all()
+        .map(|address| {
+            let byte = address.as_byte();
+            let read = Transaction::write_read(byte, vec![0], vec![0x39, 0x9F]);
+            if byte == 0x40 || byte == 0x45 {
+                read
+            } else {
+                read.with_error(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address))
+            }
+        })
+        .collect();
+    let mut i2c = I2cMock::new(&expectations);
+
+    let found: Vec<u8> = crate::scan(&mut i2c).map(Address::as_byte).collect();
+    assert_eq!(found, [0x40, 0x45]);
+
+    i2c.done();
+}
+
+/// I2C bus on which every operation fails with an error implementing `core::error::Error`
+struct FailingI2c;
+
+#[derive(Debug)]
+struct FailingI2cError;
+
+impl core::fmt::Display for FailingI2cError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "bus failure")
+    }
+}
+
+impl core::error::Error for FailingI2cError {}
+
+impl embedded_hal::i2c::Error for FailingI2cError {
+    fn kind(&self) -> embedded_hal::i2c::ErrorKind {
+        embedded_hal::i2c::ErrorKind::Other
+    }
+}
+
+impl embedded_hal::i2c::ErrorType for FailingI2c {
+    type Error = FailingI2cError;
+}
+
+impl embedded_hal::i2c::I2c for FailingI2c {
+    fn transaction(
+        &mut self,
+        _address: u8,
+        _operations: &mut [embedded_hal::i2c::Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        Err(FailingI2cError)
+    }
+}
+
+#[test]
+fn initialization_error_can_be_boxed() {
+    use crate::errors::InitializationError;
+    use std::error::Error;
+
+    // The device is borrowed, so the error is not 'static
+    fn open(i2c: &mut FailingI2c) -> Result<(), Box<dyn Error + '_>> {
+        INA219::new(i2c, Address::default())?;
+        Ok(())
+    }
+
+    let mut i2c = FailingI2c;
+    let err = open(&mut i2c).unwrap_err();
+    assert_eq!(err.to_string(), "I2C error: FailingI2cError");
+    assert!(err.source().unwrap().is::<FailingI2cError>());
+
+    let Err(err) = INA219::new(FailingI2c, Address::default()) else {
+        panic!("Initialization should fail");
+    };
+    let err: Box<dyn Error> = err.into();
+    assert!(err
+        .downcast::<InitializationError<FailingI2c, FailingI2cError>>()
+        .is_ok());
+}
+
+#[test]
+fn release_after_error() {
+    use embedded_hal::i2c::ErrorKind;
+
+    let mut ina = mock_cal(&[read_reg(RegisterName::BusVoltage, 0).with_error(ErrorKind::Other)]);
+
+    assert!(matches!(
+        ina.bus_voltage(),
+        Err(crate::errors::BusVoltageReadError::I2cError(
+            ErrorKind::Other
+        ))
+    ));
+
+    let (mut i2c, calib) = ina.release();
+    assert_eq!(
+        calib,
+        IntCalibration::new(MicroAmpere(100), 1_000_000).unwrap()
+    );
+    i2c.done();
+}
+
+#[test]
+fn shared_bus() {
+    use embedded_hal_bus::i2c::MutexDevice;
+    use std::sync::Mutex;
+
+    let other = Address::from_byte(0x41).unwrap();
+    let bus = Mutex::new(I2cMock::new(&[
+        read_reg(RegisterName::BusVoltage, bus_voltage(5_000)),
+        Transaction::write_read(other.as_byte(), vec![2], vec![0x5D, 0xC0]),
+        read_reg(RegisterName::BusVoltage, bus_voltage(5_004)),
+    ]));
+
+    let mut first = INA219::new_unchecked(MutexDevice::new(&bus), Address::default(), UnCalibrated);
+    let mut second = INA219::new_unchecked(MutexDevice::new(&bus), other, UnCalibrated);
+
+    assert_eq!(first.bus_voltage().unwrap().voltage_mv(), 5_000);
+    assert_eq!(second.bus_voltage().unwrap().voltage_mv(), 12_000);
+    assert_eq!(first.bus_voltage().unwrap().voltage_mv(), 5_004);
+
+    first.destroy();
+    second.destroy();
+    bus.into_inner().unwrap().done();
+}
+
+#[test]
+fn borrowed_bus() {
+    let mut i2c = I2cMock::new(&[read_reg(RegisterName::BusVoltage, bus_voltage(5_000))]);
+
+    let mut ina = INA219::new_unchecked(&mut i2c, Address::default(), UnCalibrated);
+    assert_eq!(ina.bus_voltage().unwrap().voltage_mv(), 5_000);
+    ina.destroy();
+
+    i2c.done();
+}
@@ -0,0 +1,179 @@
+//! Per-operation timeouts for the async driver
+//!
+//! On a shared bus a misbehaving device can keep an I2C transaction from ever completing. Wrapping
+//! the bus in a [`TimeoutI2c`] races every I2C operation against a [`DelayNs`] and returns
+//! [`TimeoutError::Timeout`] if the operation did not finish in time.
+//!
+//! Use [`INA219::with_op_timeout`] to add a timeout to an existing driver.
+
+use crate::calibration::Calibration;
+use crate::r#async::INA219;
+use core::fmt::{Debug, Display, Formatter};
+use core::future::{poll_fn, Future};
+use core::pin::pin;
+use core::task::Poll;
+use embedded_hal_async::delay::DelayNs;
+use embedded_hal_async::i2c::{Error, ErrorKind, ErrorType, I2c, Operation, SevenBitAddress};
+
+/// Error of an I2C operation that was run with a timeout
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum TimeoutError<I2cErr> {
+    /// The underlying I2C operation failed
+    I2cError(I2cErr),
+    /// The I2C operation did not finish before the timeout
+    Timeout,
+}
+
+impl<E: Error> Error for TimeoutError<E> {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Self::I2cError(err) => err.kind(),
+            Self::Timeout => ErrorKind::Other,
+        }
+    }
+}
+
+impl<E: Debug> Display for TimeoutError<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::I2cError(err) => write!(f, "I2C error: {err:?}"),
+            Self::Timeout => write!(f, "I2C operation timed out"),
+        }
+    }
+}
+
+impl<I2cErr> core::error::Error for TimeoutError<I2cErr>
+where
+    I2cErr: Debug + core::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::I2cError(err) => Some(err),
+            Self::Timeout => None,
+        }
+    }
+}
+
+/// I2C bus wrapper that aborts every operation that takes longer than a timeout
+pub struct TimeoutI2c<I2C, D> {
+    i2c: I2C,
+    delay: D,
+    timeout_us: u32,
+}
+
+impl<I2C, D> TimeoutI2c<I2C, D> {
+    /// Wrap `i2c` so every operation is aborted after `timeout_us` µs as measured by `delay`
+    pub const fn new(i2c: I2C, delay: D, timeout_us: u32) -> Self {
+        Self {
+            i2c,
+            delay,
+            timeout_us,
+        }
+    }
+
+    /// Return the wrapped I2C bus and delay
+    pub fn destroy(self) -> (I2C, D) {
+        (self.i2c, self.delay)
+    }
+}
+
+impl<I2C: ErrorType, D> ErrorType for TimeoutI2c<I2C, D> {
+    type Error = TimeoutError<I2C::Error>;
+}
+
+impl<I2C: I2c, D: DelayNs> I2c for TimeoutI2c<I2C, D> {
+    async fn read(&mut self, address: SevenBitAddress, read: &mut [u8]) -> Result<(), Self::Error> {
+        let op = self.i2c.read(address, read);
+        with_timeout(&mut self.delay, self.timeout_us, op).await
+    }
+
+    async fn write(&mut self, address: SevenBitAddress, write: &[u8]) -> Result<(), Self::Error> {
+        let op = self.i2c.write(address, write);
+        with_timeout(&mut self.delay, self.timeout_us, op).await
+    }
+
+    async fn write_read(
+        &mut self,
+        address: SevenBitAddress,
+        write: &[u8],
+        read: &mut [u8],
+    ) -> Result<(), Self::Error> {
+        let op = self.i2c.write_read(address, write, read);
+        with_timeout(&mut self.delay, self.timeout_us, op).await
+    }
+
+    async fn transaction(
+        &mut self,
+        address: SevenBitAddress,
+        operations: &mut [Operation<'_>],
+    ) -> Result<(), Self::Error> {
+        let op = self.i2c.transaction(address, operations);
+        with_timeout(&mut self.delay, self.timeout_us, op).await
+    }
+}
+
+/// Poll `op` until it finishes or the delay of `timeout_us` expires, whatever comes first
+async fn with_timeout<E>(
+    delay: &mut impl DelayNs,
+    timeout_us: u32,
+    op: impl Future<Output = Result<(), E>>,
+) -> Result<(), TimeoutError<E>> {
+    let mut op = pin!(op);
+    let mut timeout = pin!(delay.delay_us(timeout_us));
+
+    poll_fn(|cx| {
+        if let Poll::Ready(result) = op.as_mut().poll(cx) {
+            return Poll::Ready(result.map_err(TimeoutError::I2cError));
+        }
+
+        if timeout.as_mut().poll(cx).is_ready() {
+            return Poll::Ready(Err(TimeoutError::Timeout));
+        }
+
+        Poll::Pending
+    })
+    .await
+}
+
+impl<I2C, Calib> INA219<I2C, Calib>
+where
+    I2C: I2c,
+    Calib: Calibration,
+{
+    /// Abort every I2C operation of this driver that takes longer than `timeout_us` µs
+    ///
+    /// This keeps a hanging bus from blocking the task using this driver forever. Operations that
+    /// time out return [`TimeoutError::Timeout`] as their I2C error.
+    pub fn with_op_timeout<D: DelayNs>(
+        self,
+        delay: D,
+        timeout_us: u32,
+    ) -> INA219<TimeoutI2c<I2C, D>, Calib> {
+        self.map_i2c(|i2c| TimeoutI2c::new(i2c, delay, timeout_us))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{block_on, FakeI2c, PollDelay};
+
+    #[test]
+    fn times_out() {
+        let bus = FakeI2c {
+            hang: true,
+            ..FakeI2c::default()
+        };
+        let mut i2c = TimeoutI2c::new(bus, PollDelay { polls: 3 }, 1_000);
+        let result = block_on(i2c.write(0x40, &[0]));
+        assert_eq!(result, Err(TimeoutError::Timeout));
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::Other);
+    }
+
+    #[test]
+    fn finishes_in_time() {
+        let mut i2c = TimeoutI2c::new(FakeI2c::default(), PollDelay { polls: 3 }, 1_000);
+        let mut buf = [0; 2];
+        assert_eq!(block_on(i2c.write_read(0x40, &[0], &mut buf)), Ok(()));
+    }
+}